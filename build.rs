@@ -0,0 +1,432 @@
+use parse_zoneinfo::{
+    line::{ChangeTime, DaySpec, Line, LineParser, TimeSpecAndType, TimeType, Year},
+    table::{RuleInfo, Saving, Table, TableBuilder, ZoneInfo},
+    transitions::{FixedTimespan, TableTransitions},
+};
+use std::{
+    collections::BTreeMap,
+    env,
+    fmt::Write as _,
+    fs,
+    path::{Path, PathBuf},
+};
+
+const TZDATA_FILES: [&str; 9] = [
+    "africa",
+    "antarctica",
+    "asia",
+    "australasia",
+    "backward",
+    "etcetera",
+    "europe",
+    "northamerica",
+    "southamerica",
+];
+
+const SECONDS_PER_DAY: i64 = 86_400;
+
+// The days before each month in a year without February 29th.
+const DAYS_BEFORE_MONTH: [i64; 12] = [0, 31, 59, 90, 120, 151, 181, 212, 243, 273, 304, 334];
+
+// zic expands `%z` to the numeric offset, using as few digits as possible.
+fn expand_numeric_abbreviation(name: &str, offset: i64) -> String {
+    if !name.contains("%z") {
+        return String::from(name);
+    }
+    let sign = if offset < 0 { '-' } else { '+' };
+    let offset = offset.abs();
+    let (hours, minutes, seconds) = (offset / 3600, offset / 60 % 60, offset % 60);
+    let numeric = if seconds != 0 {
+        format!("{}{:02}{:02}{:02}", sign, hours, minutes, seconds)
+    } else if minutes != 0 {
+        format!("{}{:02}{:02}", sign, hours, minutes)
+    } else {
+        format!("{}{:02}", sign, hours)
+    };
+    name.replace("%z", &numeric)
+}
+
+fn format_abbreviation(timespan: &FixedTimespan) -> String {
+    expand_numeric_abbreviation(&timespan.name, timespan.total_offset())
+}
+
+// A time of day or offset as `[-]h[:mm[:ss]]`.
+fn posix_time(seconds: i64) -> String {
+    let sign = if seconds < 0 { "-" } else { "" };
+    let seconds = seconds.abs();
+    let (hours, minutes, seconds) = (seconds / 3600, seconds / 60 % 60, seconds % 60);
+    if seconds != 0 {
+        format!("{}{}:{:02}:{:02}", sign, hours, minutes, seconds)
+    } else if minutes != 0 {
+        format!("{}{}:{:02}", sign, hours, minutes)
+    } else {
+        format!("{}{}", sign, hours)
+    }
+}
+
+fn posix_rule_time(seconds: i64) -> String {
+    if seconds == 2 * 3600 {
+        String::new()
+    } else {
+        format!("/{}", posix_time(seconds))
+    }
+}
+
+// Abbreviations other than three or more letters are quoted, such as `<+0530>`.
+fn posix_abbreviation(abbreviation: &str) -> String {
+    if abbreviation.len() >= 3 && abbreviation.chars().all(|c| c.is_ascii_alphabetic()) {
+        String::from(abbreviation)
+    } else {
+        format!("<{}>", abbreviation)
+    }
+}
+
+// A rule as a POSIX date and wall-clock time, given the time in seconds after midnight on the
+// wall clock before the change. `Fri>=23` becomes `M3.4.4/26`, the day after the fourth Thursday
+// at 26:00, and the default time of 02:00 is left out, as zic writes them.
+fn posix_rule(rule: &RuleInfo, time: i64) -> Option<String> {
+    let month = rule.month as i64;
+    let (weekday, day) = match rule.day {
+        DaySpec::Ordinal(day) if month == 2 && day == 29 => return None,
+        DaySpec::Ordinal(day) => {
+            let julian = DAYS_BEFORE_MONTH[month as usize - 1] + i64::from(day);
+            return Some(format!("J{}{}", julian, posix_rule_time(time)));
+        }
+        DaySpec::Last(weekday) => {
+            return Some(format!(
+                "M{}.5.{}{}",
+                month,
+                weekday as i64,
+                posix_rule_time(time)
+            ));
+        }
+        DaySpec::FirstOnOrAfter(weekday, day) => (weekday as i64, i64::from(day)),
+        DaySpec::LastOnOrBefore(weekday, day) => (weekday as i64, i64::from(day) - 6),
+    };
+    let shift = (day - 1) % 7;
+    let week = (day - 1 - shift) / 7 + 1;
+    let time = time + shift * SECONDS_PER_DAY;
+    if day < 1 || week > 4 || time.abs() > 167 * 3600 {
+        return None;
+    }
+    Some(format!(
+        "M{}.{}.{}{}",
+        month,
+        week,
+        (weekday - shift).rem_euclid(7),
+        posix_rule_time(time)
+    ))
+}
+
+// The POSIX TZ string for the rules of the last zone line, like the footer zic writes at the end
+// of TZif files, so that instants after the precomputed transitions follow the zone's current
+// rules. `None` when the rules can't be written as one, and the last span is used instead.
+fn posix_footer(table: &Table, zone_lines: &[ZoneInfo]) -> Option<String> {
+    let zone_line = zone_lines.last()?;
+    let utc_offset = zone_line.offset;
+    let abbreviation = |save: i64, letters: Option<&String>| {
+        posix_abbreviation(&expand_numeric_abbreviation(
+            &zone_line.format.format(save, letters),
+            utc_offset + save,
+        ))
+    };
+    let fixed = |save: i64, letters: Option<&String>| {
+        Some(format!(
+            "{}{}",
+            abbreviation(save, letters),
+            posix_time(-(utc_offset + save))
+        ))
+    };
+    let rules = match &zone_line.saving {
+        Saving::NoSaving => return fixed(0, None),
+        Saving::OneOff(save) => return fixed(*save, None),
+        Saving::Multiple(rules) => &table.rulesets[rules],
+    };
+
+    let ongoing: Vec<&RuleInfo> = rules
+        .iter()
+        .filter(|rule| rule.to_year == Some(Year::Maximum))
+        .collect();
+    let (daylight_saving, standard) = match ongoing.as_slice() {
+        [] => {
+            let last_year = |rule: &RuleInfo| match (rule.from_year, rule.to_year) {
+                (_, Some(Year::Number(year))) | (Year::Number(year), _) => year,
+                _ => i64::MIN,
+            };
+            let last = rules
+                .iter()
+                .max_by_key(|rule| (last_year(rule), rule.month as i64))?;
+            return fixed(last.time_to_add, last.letters.as_ref());
+        }
+        [first, second] if first.time_to_add != 0 && second.time_to_add == 0 => (first, second),
+        [first, second] if first.time_to_add == 0 && second.time_to_add != 0 => (second, first),
+        _ => return None,
+    };
+
+    // POSIX rule times are on the wall clock in effect before each change.
+    let save = daylight_saving.time_to_add;
+    let start_time = match daylight_saving.time_type {
+        TimeType::Wall | TimeType::Standard => daylight_saving.time,
+        TimeType::UTC => daylight_saving.time + utc_offset,
+    };
+    let end_time = match standard.time_type {
+        TimeType::Wall => standard.time,
+        TimeType::Standard => standard.time + save,
+        TimeType::UTC => standard.time + utc_offset + save,
+    };
+    // The daylight saving time offset defaults to an hour ahead of standard time.
+    let daylight_saving_offset = if save == 3600 {
+        String::new()
+    } else {
+        posix_time(-(utc_offset + save))
+    };
+    Some(format!(
+        "{}{}{}{},{},{}",
+        abbreviation(0, standard.letters.as_ref()),
+        posix_time(-utc_offset),
+        abbreviation(save, daylight_saving.letters.as_ref()),
+        daylight_saving_offset,
+        posix_rule(daylight_saving, start_time)?,
+        posix_rule(standard, end_time)?
+    ))
+}
+
+// parse-zoneinfo reads every UNTIL time on the wall clock, so a zone line that ends at a time in
+// UTC (`u`) or standard time (`s`) produces a transition that is off by the offset in effect.
+// This finds those transitions and moves them to the right instant. Transitions that the move
+// passes over belong to the wrong zone line, so they are folded into the moved one.
+fn correct_until_transitions(
+    zone_lines: &[ZoneInfo],
+    first: &FixedTimespan,
+    rest: &mut Vec<(i64, FixedTimespan)>,
+) {
+    for zone_line in zone_lines {
+        let (until, time_type) = match zone_line.end_time {
+            Some(end_time @ ChangeTime::UntilTime(_, _, _, TimeSpecAndType(_, time_type))) => {
+                (end_time.to_timestamp(), time_type)
+            }
+            _ => continue,
+        };
+        let found = (0..rest.len()).find_map(|index| {
+            let before = if index == 0 {
+                first
+            } else {
+                &rest[index - 1].1
+            };
+            let (utc_offset, dst_offset) = (before.utc_offset, before.dst_offset);
+            if rest[index].0 + utc_offset + dst_offset != until {
+                return None;
+            }
+            let correction = match time_type {
+                TimeType::UTC => utc_offset + dst_offset,
+                TimeType::Standard => dst_offset,
+                TimeType::Wall => 0,
+            };
+            Some((index, rest[index].0 + correction))
+        });
+        if let Some((mut index, start)) = found {
+            rest[index].0 = start;
+            while index > 0 && rest[index - 1].0 >= start {
+                rest.remove(index - 1);
+                index -= 1;
+            }
+            while index + 1 < rest.len() && rest[index + 1].0 <= start {
+                let (_, timespan) = rest.remove(index + 1);
+                rest[index].1 = timespan;
+            }
+        }
+    }
+}
+
+// The vanguard form of tzdata gives Europe/Dublin, Africa/Windhoek and Morocco negative daylight
+// saving time, so that winter time is the one flagged as DST. Like tzdata's rearguard form, which
+// is what Moment.js's `isDST` agrees with, the negative spans are read as standard time and the
+// spans between two of them as daylight saving time.
+fn daylight_saving_flags(first: &FixedTimespan, rest: &[(i64, FixedTimespan)]) -> Vec<bool> {
+    let timespans: Vec<&FixedTimespan> = std::iter::once(first)
+        .chain(rest.iter().map(|(_, timespan)| timespan))
+        .collect();
+    let is_negative = |index: Option<usize>| {
+        index
+            .and_then(|index| timespans.get(index))
+            .is_some_and(|timespan| timespan.dst_offset < 0)
+    };
+    (0..timespans.len())
+        .map(|index| match timespans[index].dst_offset {
+            0 => is_negative(index.checked_sub(1)) && is_negative(Some(index + 1)),
+            dst_offset => dst_offset > 0,
+        })
+        .collect()
+}
+
+fn attribute<'a>(element: &'a str, name: &str) -> Option<&'a str> {
+    let start = element.find(&format!(" {}=\"", name))? + name.len() + 3;
+    let length = element[start..].find('"')?;
+    Some(&element[start..start + length])
+}
+
+// Reads the `mapZone` elements of CLDR's windowsZones.xml. Zones that the embedded tz database
+// doesn't know are left out.
+fn windows_zones(path: &Path, names: &BTreeMap<&str, usize>) -> String {
+    let contents = fs::read_to_string(path)
+        .unwrap_or_else(|error| panic!("cannot read {}: {}", path.display(), error));
+    let mut entries = Vec::new();
+    for element in contents
+        .split('<')
+        .filter(|element| element.starts_with("mapZone "))
+    {
+        let (windows_name, territory, zones) = match (
+            attribute(element, "other"),
+            attribute(element, "territory"),
+            attribute(element, "type"),
+        ) {
+            (Some(windows_name), Some(territory), Some(zones)) => (windows_name, territory, zones),
+            _ => panic!("invalid mapZone element: <{}", element),
+        };
+        let zones: Vec<&str> = zones
+            .split_whitespace()
+            .filter(|zone| names.contains_key(zone))
+            .collect();
+        if !zones.is_empty() {
+            entries.push((windows_name, territory, zones));
+        }
+    }
+
+    let mut output = String::new();
+    writeln!(
+        output,
+        "static WINDOWS_ZONES: [(&str, &str, &[&str]); {}] = [",
+        entries.len()
+    )
+    .unwrap();
+    for (windows_name, territory, zones) in entries.iter() {
+        writeln!(
+            output,
+            "    ({:?}, {:?}, &{:?}),",
+            windows_name, territory, zones
+        )
+        .unwrap();
+    }
+    writeln!(output, "];").unwrap();
+    output
+}
+
+fn main() {
+    let tzdata_directory =
+        PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap()).join(Path::new("tzdata"));
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed={}", tzdata_directory.display());
+    let windows_zones_path = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap())
+        .join(Path::new("cldr/windowsZones.xml"));
+    println!("cargo:rerun-if-changed={}", windows_zones_path.display());
+
+    let parser = LineParser::default();
+    let mut builder = TableBuilder::new();
+    for file_name in TZDATA_FILES.iter() {
+        let path = tzdata_directory.join(file_name);
+        let contents = fs::read_to_string(&path)
+            .unwrap_or_else(|error| panic!("cannot read {}: {}", path.display(), error));
+        for line in contents.lines() {
+            let line = line.split('#').next().unwrap_or_default();
+            match parser.parse_str(line).unwrap() {
+                Line::Zone(zone) => builder.add_zone_line(zone).unwrap(),
+                Line::Continuation(continuation) => {
+                    builder.add_continuation_line(continuation).unwrap()
+                }
+                Line::Rule(rule) => builder.add_rule_line(rule).unwrap(),
+                Line::Link(link) => builder.add_link_line(link).unwrap(),
+                Line::Space => {}
+            }
+        }
+    }
+    let table = builder.build();
+
+    let mut output = String::new();
+    let mut zone_indices = BTreeMap::new();
+    for (index, name) in table.zonesets.keys().enumerate() {
+        let mut timespans = table.timespans(name).unwrap();
+        correct_until_transitions(&table.zonesets[name], &timespans.first, &mut timespans.rest);
+        let is_dst = daylight_saving_flags(&timespans.first, &timespans.rest);
+        let mut types: Vec<(i64, bool, String)> = Vec::new();
+        let mut type_index = |timespan: &FixedTimespan, is_dst: bool| {
+            let local_time_type = (
+                timespan.total_offset(),
+                is_dst,
+                format_abbreviation(timespan),
+            );
+            match types
+                .iter()
+                .position(|existing| *existing == local_time_type)
+            {
+                Some(position) => position,
+                None => {
+                    types.push(local_time_type);
+                    types.len() - 1
+                }
+            }
+        };
+
+        let mut spans = vec![type_index(&timespans.first, is_dst[0])];
+        let mut transitions = Vec::new();
+        for (index, (start, timespan)) in timespans.rest.iter().enumerate() {
+            transitions.push(*start);
+            spans.push(type_index(timespan, is_dst[index + 1]));
+        }
+
+        writeln!(output, "static ZONE_{}: ZoneData = ZoneData {{", index).unwrap();
+        writeln!(output, "    transitions: &{:?},", transitions).unwrap();
+        writeln!(output, "    spans: &{:?},", spans).unwrap();
+        write!(output, "    types: &[").unwrap();
+        for (utc_offset, is_dst, abbreviation) in types.iter() {
+            write!(
+                output,
+                "ZoneType {{ utc_offset: {}, is_dst: {}, abbreviation: {:?} }}, ",
+                utc_offset, is_dst, abbreviation
+            )
+            .unwrap();
+        }
+        writeln!(output, "],").unwrap();
+        writeln!(
+            output,
+            "    footer: {:?},",
+            posix_footer(&table, &table.zonesets[name])
+        )
+        .unwrap();
+        writeln!(output, "    posix_tz: OnceLock::new(),\n}};").unwrap();
+        zone_indices.insert(name.clone(), index);
+    }
+
+    let mut names: BTreeMap<&str, usize> = zone_indices
+        .iter()
+        .map(|(name, index)| (name.as_str(), *index))
+        .collect();
+    for (link, target) in table.links.iter() {
+        let mut target = target;
+        while let Some(next) = table.links.get(target) {
+            target = next;
+        }
+        names.insert(link.as_str(), zone_indices[target]);
+    }
+
+    writeln!(
+        output,
+        "static ZONES: [(&str, &ZoneData); {}] = [",
+        names.len()
+    )
+    .unwrap();
+    for (name, index) in names.iter() {
+        writeln!(output, "    ({:?}, &ZONE_{}),", name, index).unwrap();
+    }
+    writeln!(output, "];").unwrap();
+
+    let version = fs::read_to_string(tzdata_directory.join("version")).unwrap();
+    writeln!(output, "const TZDATA_VERSION: &str = {:?};", version.trim()).unwrap();
+
+    let out_directory = PathBuf::from(env::var("OUT_DIR").unwrap());
+    fs::write(out_directory.join("zones.rs"), output).unwrap();
+    fs::write(
+        out_directory.join("windows_zones.rs"),
+        windows_zones(&windows_zones_path, &names),
+    )
+    .unwrap();
+}
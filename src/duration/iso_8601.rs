@@ -0,0 +1,321 @@
+use super::{
+    MomentDuration, DAYS_PER_400_YEARS, MONTHS_PER_400_YEARS, NANOSECONDS_PER_DAY,
+    NANOSECONDS_PER_HOUR, NANOSECONDS_PER_MINUTE, NANOSECONDS_PER_SECOND,
+};
+use crate::{MomentError, UnitOfTime};
+use chrono::Duration;
+use std::{convert::TryFrom, fmt, str::FromStr};
+
+const ISO_8601_DURATION_FORMAT: &str = "PnYnMnWnDTnHnMnS";
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+enum Designator {
+    Year,
+    Month,
+    Week,
+    Day,
+    Hour,
+    Minute,
+    Second,
+}
+
+#[derive(Debug, Default)]
+struct DurationParts {
+    years: i128,
+    months: i128,
+    weeks: i128,
+    days: i128,
+    nanoseconds: i128,
+}
+
+impl DurationParts {
+    fn add_whole(&mut self, designator: Designator, value: i128) {
+        match designator {
+            Designator::Year => self.years += value,
+            Designator::Month => self.months += value,
+            Designator::Week => self.weeks += value,
+            Designator::Day => self.days += value,
+            Designator::Hour => self.nanoseconds += value * NANOSECONDS_PER_HOUR,
+            Designator::Minute => self.nanoseconds += value * NANOSECONDS_PER_MINUTE,
+            Designator::Second => self.nanoseconds += value * NANOSECONDS_PER_SECOND,
+        }
+    }
+
+    // Fractions of calendar units are carried into the next smaller unit, the same way
+    // Moment.js bubbles fractional durations.
+    fn add_fraction(&mut self, designator: Designator, numerator: i128, denominator: i128) {
+        match designator {
+            Designator::Year => {
+                let months = numerator * 12;
+                self.months += months / denominator;
+                self.add_fraction(Designator::Month, months % denominator, denominator);
+            }
+            Designator::Month => {
+                let days = numerator * DAYS_PER_400_YEARS;
+                let denominator = denominator * MONTHS_PER_400_YEARS;
+                self.days += days / denominator;
+                self.add_fraction(Designator::Day, days % denominator, denominator);
+            }
+            Designator::Week => {
+                let days = numerator * 7;
+                self.days += days / denominator;
+                self.add_fraction(Designator::Day, days % denominator, denominator);
+            }
+            Designator::Day => self.nanoseconds += numerator * NANOSECONDS_PER_DAY / denominator,
+            Designator::Hour => self.nanoseconds += numerator * NANOSECONDS_PER_HOUR / denominator,
+            Designator::Minute => {
+                self.nanoseconds += numerator * NANOSECONDS_PER_MINUTE / denominator
+            }
+            Designator::Second => {
+                self.nanoseconds += numerator * NANOSECONDS_PER_SECOND / denominator
+            }
+        }
+    }
+
+    fn into_moment_duration(self, sign: i128) -> Option<MomentDuration> {
+        let nanoseconds = i64::try_from(self.nanoseconds * sign).ok()?;
+        Some(MomentDuration {
+            years: i64::try_from(self.years * sign).ok()?,
+            months: i64::try_from(self.months * sign).ok()?,
+            weeks: i64::try_from(self.weeks * sign).ok()?,
+            days: i64::try_from(self.days * sign).ok()?,
+            time: Duration::nanoseconds(nanoseconds),
+        })
+    }
+}
+
+/// Parses a signed decimal number at the start of `input`, returning the whole part, the
+/// fractional part as `numerator / denominator` and the number of bytes read.
+fn parse_decimal(input: &str) -> Option<(i128, i128, i128, usize)> {
+    let bytes = input.as_bytes();
+    let mut index = 0;
+    let sign = match bytes.first() {
+        Some(b'-') => {
+            index += 1;
+            -1
+        }
+        Some(b'+') => {
+            index += 1;
+            1
+        }
+        _ => 1,
+    };
+
+    let whole_start = index;
+    while index < bytes.len() && bytes[index].is_ascii_digit() {
+        index += 1;
+    }
+    let whole_digits = &input[whole_start..index];
+
+    let mut fraction_digits = "";
+    if index < bytes.len() && (bytes[index] == b'.' || bytes[index] == b',') {
+        let fraction_start = index + 1;
+        index += 1;
+        while index < bytes.len() && bytes[index].is_ascii_digit() {
+            index += 1;
+        }
+        fraction_digits = &input[fraction_start..index];
+    }
+
+    if whole_digits.is_empty() && fraction_digits.is_empty() {
+        return None;
+    }
+
+    let whole = if whole_digits.is_empty() {
+        0
+    } else {
+        whole_digits.parse::<i64>().ok()? as i128
+    };
+    // Digits beyond nanosecond precision can't be represented, so they're dropped.
+    let fraction_digits = &fraction_digits[..fraction_digits.len().min(9)];
+    let numerator = if fraction_digits.is_empty() {
+        0
+    } else {
+        fraction_digits.parse::<i128>().ok()?
+    };
+    let denominator = 10_i128.pow(fraction_digits.len() as u32);
+
+    Some((whole * sign, numerator * sign, denominator, index))
+}
+
+fn designator_for(character: u8, is_time: bool) -> Option<Designator> {
+    match (character, is_time) {
+        (b'Y', false) => Some(Designator::Year),
+        (b'M', false) => Some(Designator::Month),
+        (b'W', false) => Some(Designator::Week),
+        (b'D', false) => Some(Designator::Day),
+        (b'H', true) => Some(Designator::Hour),
+        (b'M', true) => Some(Designator::Minute),
+        (b'S', true) => Some(Designator::Second),
+        _ => None,
+    }
+}
+
+fn sign_of(value: f64) -> i8 {
+    if value > 0_f64 {
+        1
+    } else if value < 0_f64 {
+        -1
+    } else {
+        0
+    }
+}
+
+impl MomentDuration {
+    /// Parses an ISO 8601 duration such as `P1Y2M3DT4H5M6.789S`, `P3W` or `-PT90M`. Only the
+    /// smallest unit may have a fraction; fractions of years, months and weeks are carried into
+    /// smaller units.
+    pub fn from_iso_8601(input: &str) -> Result<MomentDuration, MomentError> {
+        let parse_error = |offset: usize| MomentError::Parse {
+            input: String::from(input),
+            format: Some(String::from(ISO_8601_DURATION_FORMAT)),
+            offset,
+        };
+
+        let bytes = input.as_bytes();
+        let mut index = 0;
+        let sign = match bytes.first() {
+            Some(b'-') => {
+                index += 1;
+                -1
+            }
+            Some(b'+') => {
+                index += 1;
+                1
+            }
+            _ => 1,
+        };
+        if bytes.get(index) != Some(&b'P') {
+            return Err(parse_error(index));
+        }
+        index += 1;
+
+        let mut parts = DurationParts::default();
+        let mut last_designator: Option<Designator> = None;
+        let mut has_fraction = false;
+        let mut has_components = false;
+        let mut is_time = false;
+
+        while index < bytes.len() {
+            if bytes[index] == b'T' && !is_time {
+                is_time = true;
+                index += 1;
+                if index == bytes.len() {
+                    return Err(parse_error(index));
+                }
+                continue;
+            }
+
+            if has_fraction {
+                return Err(parse_error(index));
+            }
+
+            let (whole, numerator, denominator, length) =
+                parse_decimal(&input[index..]).ok_or_else(|| parse_error(index))?;
+            let designator_index = index + length;
+            let designator = bytes
+                .get(designator_index)
+                .and_then(|character| designator_for(*character, is_time))
+                .ok_or_else(|| parse_error(designator_index))?;
+            if last_designator.is_some_and(|last| last as u8 >= designator as u8) {
+                return Err(parse_error(designator_index));
+            }
+
+            parts.add_whole(designator, whole);
+            if numerator != 0 {
+                parts.add_fraction(designator, numerator, denominator);
+            }
+            has_fraction = denominator > 1;
+            has_components = true;
+            last_designator = Some(designator);
+            index = designator_index + 1;
+        }
+
+        if !has_components {
+            return Err(parse_error(index));
+        }
+
+        parts
+            .into_moment_duration(sign)
+            .ok_or(MomentError::Overflow)
+    }
+
+    /// Formats the duration as the shortest ISO 8601 string, like Moment.js's
+    /// `duration.toISOString()`. Years are carried from months and weeks are written as days.
+    pub fn to_iso_string(&self) -> String {
+        let months = i128::from(self.years) * 12 + i128::from(self.months);
+        let days = i128::from(self.weeks) * 7 + i128::from(self.days);
+        let nanoseconds = self.total_nanoseconds();
+
+        if months == 0 && days == 0 && nanoseconds == 0 {
+            return String::from("P0D");
+        }
+
+        // Components that cancel out are written with their own signs, as if the total were
+        // positive, so that `P1DT-24H` doesn't become `P-1DT-24H`.
+        let total_sign = match sign_of(self.as_unit(UnitOfTime::Second)) {
+            0 => 1,
+            sign => sign,
+        };
+        let sign_for = |value: i128| {
+            if sign_of(value as f64) != total_sign {
+                "-"
+            } else {
+                ""
+            }
+        };
+
+        let (years, months_remainder) = (months.abs() / 12, months.abs() % 12);
+        let abs_nanoseconds = nanoseconds.abs();
+        let hours = abs_nanoseconds / NANOSECONDS_PER_HOUR;
+        let minutes = abs_nanoseconds % NANOSECONDS_PER_HOUR / NANOSECONDS_PER_MINUTE;
+        let seconds = abs_nanoseconds % NANOSECONDS_PER_MINUTE / NANOSECONDS_PER_SECOND;
+        let fraction = abs_nanoseconds % NANOSECONDS_PER_SECOND;
+
+        let mut iso_string = String::from(if total_sign < 0 { "-P" } else { "P" });
+        if years != 0 {
+            iso_string.push_str(&format!("{}{}Y", sign_for(months), years));
+        }
+        if months_remainder != 0 {
+            iso_string.push_str(&format!("{}{}M", sign_for(months), months_remainder));
+        }
+        if days != 0 {
+            iso_string.push_str(&format!("{}{}D", sign_for(days), days.abs()));
+        }
+        if hours != 0 || minutes != 0 || seconds != 0 || fraction != 0 {
+            iso_string.push('T');
+        }
+        if hours != 0 {
+            iso_string.push_str(&format!("{}{}H", sign_for(nanoseconds), hours));
+        }
+        if minutes != 0 {
+            iso_string.push_str(&format!("{}{}M", sign_for(nanoseconds), minutes));
+        }
+        if seconds != 0 || fraction != 0 {
+            let fraction_string = format!("{:09}", fraction);
+            let fraction_string = fraction_string.trim_end_matches('0');
+            iso_string.push_str(&format!(
+                "{}{}{}{}S",
+                sign_for(nanoseconds),
+                seconds,
+                if fraction_string.is_empty() { "" } else { "." },
+                fraction_string
+            ));
+        }
+        iso_string
+    }
+}
+
+impl FromStr for MomentDuration {
+    type Err = MomentError;
+
+    fn from_str(input: &str) -> Result<MomentDuration, MomentError> {
+        MomentDuration::from_iso_8601(input)
+    }
+}
+
+impl fmt::Display for MomentDuration {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.to_iso_string())
+    }
+}
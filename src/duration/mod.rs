@@ -0,0 +1,252 @@
+mod iso_8601;
+mod normalize;
+
+use crate::{MomentError, UnitOfTime};
+use chrono::Duration;
+use lazy_static::lazy_static;
+use regex::Regex;
+
+lazy_static! {
+    static ref COMPACT_DURATION_PART: Regex =
+        Regex::new(r"^\s*([+-]?\d+)\s*(ms|us|ns|y|Q|M|w|d|h|m|s)").unwrap();
+}
+
+const COMPACT_DURATION_FORMAT: &str = "<number><y|Q|M|w|d|h|m|s|ms|us|ns>...";
+
+const NANOSECONDS_PER_MILLISECOND: i128 = 1_000_000;
+const NANOSECONDS_PER_SECOND: i128 = 1_000 * NANOSECONDS_PER_MILLISECOND;
+const NANOSECONDS_PER_MINUTE: i128 = 60 * NANOSECONDS_PER_SECOND;
+const NANOSECONDS_PER_HOUR: i128 = 60 * NANOSECONDS_PER_MINUTE;
+const NANOSECONDS_PER_DAY: i128 = 24 * NANOSECONDS_PER_HOUR;
+
+// Moment.js converts between days and months with the average length of a month over the
+// 400 year Gregorian cycle: 146097 days / 4800 months.
+const DAYS_PER_400_YEARS: i128 = 146_097;
+const MONTHS_PER_400_YEARS: i128 = 4800;
+
+/// A duration that keeps calendar units apart from fixed-length time, so that adding a month to
+/// a `Moment` moves it to the same day of the next month instead of by a fixed number of weeks.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct MomentDuration {
+    pub years: i64,
+    pub months: i64,
+    pub weeks: i64,
+    pub days: i64,
+    pub time: Duration,
+}
+
+impl Default for MomentDuration {
+    fn default() -> MomentDuration {
+        MomentDuration {
+            years: 0,
+            months: 0,
+            weeks: 0,
+            days: 0,
+            time: Duration::zero(),
+        }
+    }
+}
+
+impl MomentDuration {
+    pub fn new() -> MomentDuration {
+        MomentDuration::default()
+    }
+
+    pub fn checked_from_unit(value: i64, unit: UnitOfTime) -> Result<MomentDuration, MomentError> {
+        let mut moment_duration = MomentDuration::default();
+        match unit {
+            UnitOfTime::Nanosecond => moment_duration.time = Duration::nanoseconds(value),
+            UnitOfTime::Microsecond => moment_duration.time = Duration::microseconds(value),
+            UnitOfTime::Millisecond => {
+                moment_duration.time =
+                    Duration::try_milliseconds(value).ok_or(MomentError::Overflow)?
+            }
+            UnitOfTime::Second => {
+                moment_duration.time = Duration::try_seconds(value).ok_or(MomentError::Overflow)?
+            }
+            UnitOfTime::Minute => {
+                moment_duration.time = Duration::try_minutes(value).ok_or(MomentError::Overflow)?
+            }
+            UnitOfTime::Hour => {
+                moment_duration.time = Duration::try_hours(value).ok_or(MomentError::Overflow)?
+            }
+            UnitOfTime::Day => moment_duration.days = value,
+            UnitOfTime::Week | UnitOfTime::IsoWeek => moment_duration.weeks = value,
+            UnitOfTime::Month => moment_duration.months = value,
+            UnitOfTime::Quarter => {
+                moment_duration.months = value.checked_mul(3).ok_or(MomentError::Overflow)?
+            }
+            UnitOfTime::Year => moment_duration.years = value,
+        }
+        Ok(moment_duration)
+    }
+
+    /// Sums `(value, unit)` pairs into a single duration. Repeated units are added together.
+    pub fn checked_from_units<I: IntoIterator<Item = (i64, UnitOfTime)>>(
+        units: I,
+    ) -> Result<MomentDuration, MomentError> {
+        units
+            .into_iter()
+            .try_fold(MomentDuration::new(), |moment_duration, (value, unit)| {
+                moment_duration.checked_add_unit(value, unit)
+            })
+    }
+
+    pub fn from_units<I: IntoIterator<Item = (i64, UnitOfTime)>>(units: I) -> MomentDuration {
+        MomentDuration::checked_from_units(units).unwrap()
+    }
+
+    /// Parses a compact duration such as `"5d2h"` or `"1y -3M"`. Units are `y`, `Q`, `M`, `w`,
+    /// `d`, `h`, `m`, `s`, `ms`, `us` and `ns`, matching Moment.js's shorthand keys.
+    pub fn from_compact(input: &str) -> Result<MomentDuration, MomentError> {
+        let parse_error = |offset: usize| MomentError::Parse {
+            input: String::from(input),
+            format: Some(String::from(COMPACT_DURATION_FORMAT)),
+            offset,
+        };
+
+        let mut moment_duration = MomentDuration::new();
+        let mut remaining = input;
+        while !remaining.trim().is_empty() {
+            let offset = input.len() - remaining.len();
+            let captures = COMPACT_DURATION_PART
+                .captures(remaining)
+                .ok_or_else(|| parse_error(offset))?;
+            let value = captures[1]
+                .parse::<i64>()
+                .map_err(|_| parse_error(offset))?;
+            let unit = match &captures[2] {
+                "y" => UnitOfTime::Year,
+                "Q" => UnitOfTime::Quarter,
+                "M" => UnitOfTime::Month,
+                "w" => UnitOfTime::Week,
+                "d" => UnitOfTime::Day,
+                "h" => UnitOfTime::Hour,
+                "m" => UnitOfTime::Minute,
+                "s" => UnitOfTime::Second,
+                "ms" => UnitOfTime::Millisecond,
+                "us" => UnitOfTime::Microsecond,
+                _ => UnitOfTime::Nanosecond,
+            };
+            moment_duration = moment_duration.checked_add_unit(value, unit)?;
+            remaining = &remaining[captures[0].len()..];
+        }
+        Ok(moment_duration)
+    }
+
+    pub fn checked_add_unit(
+        self,
+        value: i64,
+        unit: UnitOfTime,
+    ) -> Result<MomentDuration, MomentError> {
+        self.checked_add(&MomentDuration::checked_from_unit(value, unit)?)
+    }
+
+    fn add_unit(self, value: i64, unit: UnitOfTime) -> MomentDuration {
+        self.checked_add_unit(value, unit).unwrap()
+    }
+
+    pub fn years(self, years: i64) -> MomentDuration {
+        self.add_unit(years, UnitOfTime::Year)
+    }
+
+    pub fn quarters(self, quarters: i64) -> MomentDuration {
+        self.add_unit(quarters, UnitOfTime::Quarter)
+    }
+
+    pub fn months(self, months: i64) -> MomentDuration {
+        self.add_unit(months, UnitOfTime::Month)
+    }
+
+    pub fn weeks(self, weeks: i64) -> MomentDuration {
+        self.add_unit(weeks, UnitOfTime::Week)
+    }
+
+    pub fn days(self, days: i64) -> MomentDuration {
+        self.add_unit(days, UnitOfTime::Day)
+    }
+
+    pub fn hours(self, hours: i64) -> MomentDuration {
+        self.add_unit(hours, UnitOfTime::Hour)
+    }
+
+    pub fn minutes(self, minutes: i64) -> MomentDuration {
+        self.add_unit(minutes, UnitOfTime::Minute)
+    }
+
+    pub fn seconds(self, seconds: i64) -> MomentDuration {
+        self.add_unit(seconds, UnitOfTime::Second)
+    }
+
+    pub fn milliseconds(self, milliseconds: i64) -> MomentDuration {
+        self.add_unit(milliseconds, UnitOfTime::Millisecond)
+    }
+
+    pub fn microseconds(self, microseconds: i64) -> MomentDuration {
+        self.add_unit(microseconds, UnitOfTime::Microsecond)
+    }
+
+    pub fn nanoseconds(self, nanoseconds: i64) -> MomentDuration {
+        self.add_unit(nanoseconds, UnitOfTime::Nanosecond)
+    }
+
+    /// The years and months of this duration as a number of months.
+    pub fn total_months(&self) -> Result<i64, MomentError> {
+        self.years
+            .checked_mul(12)
+            .and_then(|months| months.checked_add(self.months))
+            .ok_or(MomentError::Overflow)
+    }
+
+    /// The weeks and days of this duration as a number of days.
+    pub fn total_days(&self) -> Result<i64, MomentError> {
+        self.weeks
+            .checked_mul(7)
+            .and_then(|days| days.checked_add(self.days))
+            .ok_or(MomentError::Overflow)
+    }
+
+    pub fn checked_add(&self, other: &MomentDuration) -> Result<MomentDuration, MomentError> {
+        Ok(MomentDuration {
+            years: self
+                .years
+                .checked_add(other.years)
+                .ok_or(MomentError::Overflow)?,
+            months: self
+                .months
+                .checked_add(other.months)
+                .ok_or(MomentError::Overflow)?,
+            weeks: self
+                .weeks
+                .checked_add(other.weeks)
+                .ok_or(MomentError::Overflow)?,
+            days: self
+                .days
+                .checked_add(other.days)
+                .ok_or(MomentError::Overflow)?,
+            time: self
+                .time
+                .checked_add(&other.time)
+                .ok_or(MomentError::Overflow)?,
+        })
+    }
+}
+
+impl From<Duration> for MomentDuration {
+    fn from(time: Duration) -> MomentDuration {
+        MomentDuration {
+            time,
+            ..MomentDuration::default()
+        }
+    }
+}
+
+pub fn checked_duration<I: IntoIterator<Item = (i64, UnitOfTime)>>(
+    units: I,
+) -> Result<MomentDuration, MomentError> {
+    MomentDuration::checked_from_units(units)
+}
+
+pub fn duration<I: IntoIterator<Item = (i64, UnitOfTime)>>(units: I) -> MomentDuration {
+    MomentDuration::from_units(units)
+}
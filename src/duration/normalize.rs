@@ -0,0 +1,125 @@
+use super::{
+    MomentDuration, DAYS_PER_400_YEARS, MONTHS_PER_400_YEARS, NANOSECONDS_PER_DAY,
+    NANOSECONDS_PER_HOUR, NANOSECONDS_PER_MILLISECOND, NANOSECONDS_PER_MINUTE,
+    NANOSECONDS_PER_SECOND,
+};
+use crate::UnitOfTime;
+use chrono::Duration;
+
+fn days_to_months(days: f64) -> f64 {
+    days * MONTHS_PER_400_YEARS as f64 / DAYS_PER_400_YEARS as f64
+}
+
+fn months_to_days(months: f64) -> f64 {
+    months * DAYS_PER_400_YEARS as f64 / MONTHS_PER_400_YEARS as f64
+}
+
+fn abs_floor(number: f64) -> f64 {
+    number.trunc()
+}
+
+fn abs_ceil(number: f64) -> f64 {
+    if number < 0_f64 {
+        number.floor()
+    } else {
+        number.ceil()
+    }
+}
+
+impl MomentDuration {
+    pub(crate) fn total_nanoseconds(&self) -> i128 {
+        i128::from(self.time.num_seconds()) * NANOSECONDS_PER_SECOND
+            + i128::from(self.time.subsec_nanos())
+    }
+
+    /// Carries overflow between units the way Moment.js "bubbles" durations, so that 90 minutes
+    /// become 1 hour 30 minutes and 14 months become 1 year 2 months. Weeks are folded into days.
+    ///
+    /// If the calendar and time parts have different signs they are first collapsed into time,
+    /// using Moment.js's month length approximation.
+    pub fn normalize(&self) -> MomentDuration {
+        let mut nanoseconds = self.total_nanoseconds();
+        let mut days = i128::from(self.weeks) * 7 + i128::from(self.days);
+        let mut months = i128::from(self.years) * 12 + i128::from(self.months);
+
+        let all_positive = nanoseconds >= 0 && days >= 0 && months >= 0;
+        let all_negative = nanoseconds <= 0 && days <= 0 && months <= 0;
+        if !all_positive && !all_negative {
+            let days_in_months = abs_ceil(months_to_days(months as f64) + days as f64);
+            nanoseconds += days_in_months as i128 * NANOSECONDS_PER_DAY;
+            days = 0;
+            months = 0;
+        }
+
+        days += nanoseconds / NANOSECONDS_PER_DAY;
+        let time = nanoseconds % NANOSECONDS_PER_DAY;
+
+        let months_from_days = abs_floor(days_to_months(days as f64)) as i128;
+        months += months_from_days;
+        days -= abs_ceil(months_to_days(months_from_days as f64)) as i128;
+
+        MomentDuration {
+            years: (months / 12) as i64,
+            months: (months % 12) as i64,
+            weeks: 0,
+            days: days as i64,
+            time: Duration::nanoseconds(time as i64),
+        }
+    }
+
+    /// The component of the normalized duration for `unit`, like Moment.js's `duration.hours()`.
+    /// Weeks are the whole weeks within the days component, and quarters the whole quarters
+    /// within the months component.
+    pub fn get(&self, unit: UnitOfTime) -> i64 {
+        let normalized = self.normalize();
+        let time = normalized.total_nanoseconds();
+        let component = match unit {
+            UnitOfTime::Year => return normalized.years,
+            UnitOfTime::Quarter => return normalized.months / 3,
+            UnitOfTime::Month => return normalized.months,
+            UnitOfTime::Week | UnitOfTime::IsoWeek => return normalized.days / 7,
+            UnitOfTime::Day => return normalized.days,
+            UnitOfTime::Hour => time / NANOSECONDS_PER_HOUR,
+            UnitOfTime::Minute => time % NANOSECONDS_PER_HOUR / NANOSECONDS_PER_MINUTE,
+            UnitOfTime::Second => time % NANOSECONDS_PER_MINUTE / NANOSECONDS_PER_SECOND,
+            UnitOfTime::Millisecond => time % NANOSECONDS_PER_SECOND / NANOSECONDS_PER_MILLISECOND,
+            UnitOfTime::Microsecond => time % NANOSECONDS_PER_MILLISECOND / 1000,
+            UnitOfTime::Nanosecond => time % 1000,
+        };
+        component as i64
+    }
+
+    /// The whole duration expressed in `unit`, like Moment.js's `duration.as('hours')`.
+    /// Converting between months and days uses Moment.js's average month length.
+    pub fn as_unit(&self, unit: UnitOfTime) -> f64 {
+        let nanoseconds = self.total_nanoseconds() as f64;
+        let days = (i128::from(self.weeks) * 7 + i128::from(self.days)) as f64;
+        let months = (i128::from(self.years) * 12 + i128::from(self.months)) as f64;
+
+        match unit {
+            UnitOfTime::Year | UnitOfTime::Quarter | UnitOfTime::Month => {
+                let days = days + nanoseconds / NANOSECONDS_PER_DAY as f64;
+                let months = months + days_to_months(days);
+                match unit {
+                    UnitOfTime::Year => months / 12_f64,
+                    UnitOfTime::Quarter => months / 3_f64,
+                    _ => months,
+                }
+            }
+            _ => {
+                let days = days + months_to_days(months).round();
+                let unit_nanoseconds = match unit {
+                    UnitOfTime::Week | UnitOfTime::IsoWeek => NANOSECONDS_PER_DAY * 7,
+                    UnitOfTime::Day => NANOSECONDS_PER_DAY,
+                    UnitOfTime::Hour => NANOSECONDS_PER_HOUR,
+                    UnitOfTime::Minute => NANOSECONDS_PER_MINUTE,
+                    UnitOfTime::Second => NANOSECONDS_PER_SECOND,
+                    UnitOfTime::Millisecond => NANOSECONDS_PER_MILLISECOND,
+                    UnitOfTime::Microsecond => 1000,
+                    _ => 1,
+                } as f64;
+                (days * NANOSECONDS_PER_DAY as f64 + nanoseconds) / unit_nanoseconds
+            }
+        }
+    }
+}
@@ -0,0 +1,81 @@
+use std::{error::Error, fmt};
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum MomentError {
+    /// The input could not be parsed. `offset` is the byte offset in `input` where parsing
+    /// stopped matching `format`. `format` is `None` for the RFC 3339 / RFC 2822 fallback, which
+    /// does not report a position, and `offset` is then 0.
+    Parse {
+        input: String,
+        format: Option<String>,
+        offset: usize,
+    },
+    /// The result of an arithmetic operation does not fit in the supported date range.
+    Overflow,
+    /// A date or time component is outside of its valid range, such as a 13th month.
+    OutOfRange { component: &'static str, value: i64 },
+    /// A formatting token that cannot be used in the requested operation.
+    UnsupportedToken(String),
+    /// The name is not a time zone in the tz database.
+    UnknownTimeZone(String),
+    /// The time zone data could not be read or is corrupt.
+    InvalidTimeZone { name: String, reason: &'static str },
+    /// The wall-clock time is skipped by a daylight saving time change and
+    /// `Disambiguation::Reject` was requested.
+    NonexistentLocalTime(String),
+    /// The wall-clock time is repeated by a daylight saving time change and
+    /// `Disambiguation::Reject` was requested.
+    AmbiguousLocalTime(String),
+    /// No registered locale matches the tag or any of its fallbacks.
+    UnknownLocale(String),
+    /// Locale data could not be read or does not describe a complete locale.
+    InvalidLocale(String),
+}
+
+impl fmt::Display for MomentError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MomentError::Parse {
+                input,
+                format: Some(format),
+                offset,
+            } => write!(
+                f,
+                "Date, \"{}\", could not be parsed with format string \"{}\" at byte {}.",
+                input, format, offset
+            ),
+            MomentError::Parse {
+                input,
+                format: None,
+                ..
+            } => write!(
+                f,
+                "Could not parse date, \"{}\", as RFC 3339 / ISO 8601 or RFC 2822.",
+                input
+            ),
+            MomentError::Overflow => write!(f, "Date is outside of the supported range."),
+            MomentError::OutOfRange { component, value } => {
+                write!(f, "{} is out of range for the {}.", value, component)
+            }
+            MomentError::UnsupportedToken(token) => {
+                write!(f, "The \"{}\" token is not supported.", token)
+            }
+            MomentError::UnknownTimeZone(name) => {
+                write!(f, "\"{}\" is not a known time zone.", name)
+            }
+            MomentError::InvalidTimeZone { name, reason } => {
+                write!(f, "The time zone \"{}\" is invalid: {}.", name, reason)
+            }
+            MomentError::NonexistentLocalTime(local) => {
+                write!(f, "\"{}\" does not exist in this time zone.", local)
+            }
+            MomentError::AmbiguousLocalTime(local) => {
+                write!(f, "\"{}\" is ambiguous in this time zone.", local)
+            }
+            MomentError::UnknownLocale(tag) => write!(f, "\"{}\" is not a known locale.", tag),
+            MomentError::InvalidLocale(reason) => write!(f, "The locale is invalid: {}.", reason),
+        }
+    }
+}
+
+impl Error for MomentError {}
@@ -1,9 +1,12 @@
 mod duration;
+mod error;
 pub mod locale;
 pub mod locales;
 mod moment;
+pub mod tz;
 mod unit_of_time;
 
-pub use crate::duration::duration;
-pub use crate::moment::Moment;
+pub use crate::duration::{checked_duration, duration, MomentDuration};
+pub use crate::error::MomentError;
+pub use crate::moment::{Moment, UtcOffset};
 pub use crate::unit_of_time::UnitOfTime;
@@ -1,24 +1,26 @@
 use super::CalendarFormat;
+use serde::Deserialize;
+use std::borrow::Cow;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Deserialize)]
 pub struct Calendar {
-    pub same_day: &'static str,
-    pub next_day: &'static str,
-    pub next_week: &'static str,
-    pub last_day: &'static str,
-    pub last_week: &'static str,
-    pub same_else: &'static str,
+    pub same_day: Cow<'static, str>,
+    pub next_day: Cow<'static, str>,
+    pub next_week: Cow<'static, str>,
+    pub last_day: Cow<'static, str>,
+    pub last_week: Cow<'static, str>,
+    pub same_else: Cow<'static, str>,
 }
 
 impl Calendar {
-    pub fn get_format_for_calendar_format(&self, calendar_format: CalendarFormat) -> &'static str {
+    pub fn get_format_for_calendar_format(&self, calendar_format: CalendarFormat) -> &str {
         match calendar_format {
-            CalendarFormat::SameDay => self.same_day,
-            CalendarFormat::NextDay => self.next_day,
-            CalendarFormat::NextWeek => self.next_week,
-            CalendarFormat::LastDay => self.last_day,
-            CalendarFormat::LastWeek => self.last_week,
-            CalendarFormat::SameElse => self.same_else,
+            CalendarFormat::SameDay => &self.same_day,
+            CalendarFormat::NextDay => &self.next_day,
+            CalendarFormat::NextWeek => &self.next_week,
+            CalendarFormat::LastDay => &self.last_day,
+            CalendarFormat::LastWeek => &self.last_week,
+            CalendarFormat::SameElse => &self.same_else,
         }
     }
 }
@@ -0,0 +1,51 @@
+use super::Locale;
+use crate::MomentError;
+use regex::Regex;
+use serde::{de::Error, Deserialize, Deserializer};
+#[cfg(any(feature = "json", feature = "toml"))]
+use std::fs;
+use std::path::Path;
+
+pub(super) fn deserialize_regex<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Regex, D::Error> {
+    let pattern = String::deserialize(deserializer)?;
+    Regex::new(&pattern).map_err(D::Error::custom)
+}
+
+#[cfg(any(feature = "json", feature = "toml"))]
+fn read_locale_file(path: &Path) -> Result<String, MomentError> {
+    fs::read_to_string(path).map_err(|error| {
+        MomentError::InvalidLocale(format!("{} could not be read: {}", path.display(), error))
+    })
+}
+
+impl Locale {
+    /// Reads a locale from JSON whose keys are the names of the `Locale` fields.
+    #[cfg(feature = "json")]
+    pub fn from_json(data: &str) -> Result<Locale, MomentError> {
+        serde_json::from_str(data).map_err(|error| MomentError::InvalidLocale(error.to_string()))
+    }
+
+    /// Reads a locale from TOML whose keys are the names of the `Locale` fields.
+    #[cfg(feature = "toml")]
+    pub fn from_toml(data: &str) -> Result<Locale, MomentError> {
+        toml::from_str(data).map_err(|error| MomentError::InvalidLocale(error.to_string()))
+    }
+
+    /// Reads a locale from a `.json` or `.toml` file. The locale still has to be passed to
+    /// [`register_locale`](super::register_locale) to be found by name.
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Locale, MomentError> {
+        let path = path.as_ref();
+        match path.extension().and_then(|extension| extension.to_str()) {
+            #[cfg(feature = "json")]
+            Some("json") => Locale::from_json(&read_locale_file(path)?),
+            #[cfg(feature = "toml")]
+            Some("toml") => Locale::from_toml(&read_locale_file(path)?),
+            _ => Err(MomentError::InvalidLocale(format!(
+                "{} is not a supported locale file",
+                path.display()
+            ))),
+        }
+    }
+}
@@ -1,22 +1,26 @@
-#[derive(Debug, Clone)]
+use serde::Deserialize;
+use std::borrow::Cow;
+
+#[allow(non_snake_case)]
+#[derive(Debug, Clone, Deserialize)]
 pub struct LongDateFormat {
-    pub LT: &'static str,
-    pub LTS: &'static str,
-    pub L: &'static str,
-    pub LL: &'static str,
-    pub LLL: &'static str,
-    pub LLLL: &'static str,
+    pub LT: Cow<'static, str>,
+    pub LTS: Cow<'static, str>,
+    pub L: Cow<'static, str>,
+    pub LL: Cow<'static, str>,
+    pub LLL: Cow<'static, str>,
+    pub LLLL: Cow<'static, str>,
 }
 
 impl LongDateFormat {
-    pub fn get_by_key(&self, key: &str) -> Option<&'static str> {
+    pub fn get_by_key(&self, key: &str) -> Option<&str> {
         match key {
-            "LT" => Some(self.LT),
-            "LTS" => Some(self.LTS),
-            "L" => Some(self.L),
-            "LL" => Some(self.LL),
-            "LLL" => Some(self.LLL),
-            "LLLL" => Some(self.LLLL),
+            "LT" => Some(&self.LT),
+            "LTS" => Some(&self.LTS),
+            "L" => Some(&self.L),
+            "LL" => Some(&self.LL),
+            "LLL" => Some(&self.LLL),
+            "LLLL" => Some(&self.LLLL),
             _ => None,
         }
     }
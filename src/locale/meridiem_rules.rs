@@ -0,0 +1,29 @@
+use serde::Deserialize;
+use std::borrow::Cow;
+
+/// One period of the day in [`Meridiem`], used for times before `before`, written as
+/// `hour * 100 + minute`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct MeridiemRule {
+    pub before: u32,
+    pub text: Cow<'static, str>,
+}
+
+/// The text for the `A` and `a` tokens. The first rule whose `before` is later than the time is
+/// used, or `default` after the last one.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Meridiem {
+    #[serde(default)]
+    pub rules: Vec<MeridiemRule>,
+    pub default: Cow<'static, str>,
+}
+
+impl Meridiem {
+    pub fn format(&self, hour: u32, minute: u32) -> &str {
+        let time = hour * 100 + minute;
+        self.rules
+            .iter()
+            .find(|rule| time < rule.before)
+            .map_or(&self.default, |rule| &rule.text)
+    }
+}
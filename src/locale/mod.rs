@@ -1,32 +1,69 @@
 mod calendar_format;
 mod calendar_strings;
+mod load;
 mod long_date_format_strings;
+mod meridiem_rules;
 mod month_strings;
+mod negotiation;
+mod ordinal_rules;
+mod parse;
+mod plural_rules;
+mod registry;
+mod relative_time;
 mod relative_time_strings;
+mod time_zone_name_strings;
 mod week_config;
 mod weekday_strings;
 
+pub(crate) use self::parse::parse_utc_offset;
 pub use self::{
-    calendar_format::*, calendar_strings::*, long_date_format_strings::*, month_strings::*,
-    relative_time_strings::*, week_config::*, weekday_strings::*,
+    calendar_format::*, calendar_strings::*, long_date_format_strings::*, meridiem_rules::*,
+    month_strings::*, negotiation::*, ordinal_rules::*, parse::ParseMode, plural_rules::*,
+    registry::*, relative_time::*, relative_time_strings::*, time_zone_name_strings::*,
+    week_config::*, weekday_strings::*,
 };
-use crate::{Moment, UnitOfTime};
+use crate::{tz::OffsetName, Moment, MomentError, UnitOfTime};
 use chrono::prelude::*;
 use lazy_static::lazy_static;
 use num_integer::div_mod_floor;
 use regex::{Captures, Regex};
-use std::fmt::Debug;
+use serde::Deserialize;
+use std::{borrow::Cow, fmt::Debug};
+
+// Every formatting token. Any other character in a format string is written as it is.
+const TOKEN_PATTERN: &str = r"[Hh]mm(ss)?|Mo|MM?M?M?|Do|DDDo|DD?D?D?|ddd?d?|do?|w[o|w]?|W[o|W]?|Qo?|YYYYYY|YYYYY|YYYY|YY|Y|gg(ggg?)?|GG(GGG?)?|e|E|a|A|hh?|HH?|kk?|mm?|ss?|S{1,9}|x|X|zz?|ZZ?";
 
 lazy_static! {
-    static ref FORMATTING_TOKENS: Regex = Regex::new(r"(\[[^\[]*\])|(\\)?([Hh]mm(ss)?|Mo|MM?M?M?|Do|DDDo|DD?D?D?|ddd?d?|do?|w[o|w]?|W[o|W]?|Qo?|YYYYYY|YYYYY|YYYY|YY|gg(ggg?)?|GG(GGG?)?|e|E|a|A|hh?|HH?|kk?|mm?|ss?|S{1,9}|x|X|zz?|ZZ?|.)").unwrap();
-    static ref LOCALE_FORMATTING_TOKENS: Regex = Regex::new(r"(\[[^\[]*\])|(\\)?(LTS|LT|LL?L?L?|l{1,4})").unwrap();
+    static ref FORMATTING_TOKENS: Regex =
+        Regex::new(&format!(r"(\[[^\[]*\])|(\\)?({}|.)", TOKEN_PATTERN)).unwrap();
+    static ref TOKEN: Regex = Regex::new(&format!("^(?:{})$", TOKEN_PATTERN)).unwrap();
+    static ref LOCALE_FORMATTING_TOKENS: Regex =
+        Regex::new(r"(\[[^\[]*\])|(\\)?(LTS|LT|LL?L?L?|l{1,4})").unwrap();
     static ref ESCAPED_TEXT: Regex = Regex::new(r"\[[\s\S]").unwrap();
     static ref ESCAPED_TEXT_DELIMETERS: Regex = Regex::new(r"^\[|\]$").unwrap();
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum FormatPiece {
+    Literal(String),
+    Token(String),
+}
+
+/// A format string that has been split into tokens by [`Locale::compile_format`]. Long date
+/// formats such as `LLL` are expanded with the locale that compiled it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompiledFormat {
+    pieces: Vec<FormatPiece>,
+}
+
+/// The strings and rules for formatting and parsing in one language. Besides the compiled-in
+/// locales, a locale can be read from JSON or TOML with the same field names, see
+/// [`Locale::from_file`].
+#[derive(Debug, Clone, Deserialize)]
 pub struct Locale {
-    pub invalid_date: &'static str,
+    /// The BCP 47 tag the locale is registered under, such as `en-gb`.
+    pub name: Cow<'static, str>,
+    pub invalid_date: Cow<'static, str>,
     pub months: MonthStrings,
     pub months_short: MonthStrings,
     pub weekdays: WeekDayStrings,
@@ -35,10 +72,18 @@ pub struct Locale {
     pub long_date_format: LongDateFormat,
     pub calendar: Calendar,
     pub relative_time: RelativeTime,
+    #[serde(default)]
+    pub time_zone_names: TimeZoneNames,
+    #[serde(deserialize_with = "load::deserialize_regex")]
     pub day_of_month_ordinal_parse: Regex,
-    pub ordinal: fn(i32) -> String,
+    pub ordinal: Ordinal,
     pub week: Week,
-    pub meridiem: fn(u32, u32) -> String,
+    pub meridiem: Meridiem,
+    #[serde(deserialize_with = "load::deserialize_regex")]
+    pub meridiem_parse: Regex,
+    /// Matches the meridiems, as read by `meridiem_parse`, that are after noon.
+    #[serde(deserialize_with = "load::deserialize_regex")]
+    pub pm_parse: Regex,
 }
 
 impl Locale {
@@ -79,115 +124,135 @@ impl Locale {
         &self,
         token: &str,
         moment: &Moment<T>,
-    ) -> Option<String> {
+    ) -> Result<String, MomentError>
+    where
+        T::Offset: OffsetName,
+    {
         match token {
             // Month
-            "M" => Some(format!("{}", moment.month())),
-            "Mo" => Some((self.ordinal)(moment.month() as i32)),
-            "MM" => Some(format!("{:02}", moment.month())),
-            "MMM" => Some(String::from(
-                self.months_short.get_index(moment.month0()).unwrap(),
-            )),
-            "MMMM" => Some(String::from(
-                self.months.get_index(moment.month0()).unwrap(),
-            )),
+            "M" => Ok(format!("{}", moment.month())),
+            "Mo" => Ok(self.ordinal.format(moment.month() as i32)),
+            "MM" => Ok(format!("{:02}", moment.month())),
+            "MMM" => missing_name(self.months_short.get_index(moment.month0()), "month"),
+            "MMMM" => missing_name(self.months.get_index(moment.month0()), "month"),
 
             // Quarter
-            "Q" => Some(format!("{}", (moment.month() as f32 / 3_f32).ceil() as u32)),
-            "Qo" => Some((self.ordinal)((moment.month() as f32 / 3_f32).ceil() as i32)),
+            "Q" => Ok(format!("{}", (moment.month() as f32 / 3_f32).ceil() as u32)),
+            "Qo" => Ok(self
+                .ordinal
+                .format((moment.month() as f32 / 3_f32).ceil() as i32)),
 
             // Day of Month
-            "D" => Some(format!("{}", moment.day())),
-            "Do" => Some((self.ordinal)(moment.day() as i32)),
-            "DD" => Some(format!("{:02}", moment.day())),
+            "D" => Ok(format!("{}", moment.day())),
+            "Do" => Ok(self.ordinal.format(moment.day() as i32)),
+            "DD" => Ok(format!("{:02}", moment.day())),
 
             // Day of Year
-            "DDD" => Some(format!("{}", moment.ordinal())),
-            "DDDo" => Some((self.ordinal)(moment.ordinal() as i32)),
-            "DDDD" => Some(format!("{:03}", moment.ordinal())),
+            "DDD" => Ok(format!("{}", moment.ordinal())),
+            "DDDo" => Ok(self.ordinal.format(moment.ordinal() as i32)),
+            "DDDD" => Ok(format!("{:03}", moment.ordinal())),
 
             // Day of Week
-            "d" => Some(format!("{}", moment.locale_aware_day_of_week())),
-            "do" => Some((self.ordinal)(moment.locale_aware_day_of_week() as i32)),
-            "dd" => Some(String::from(
+            "d" => Ok(format!("{}", moment.locale_aware_day_of_week())),
+            "do" => Ok(self
+                .ordinal
+                .format(moment.locale_aware_day_of_week() as i32)),
+            "dd" => missing_name(
                 self.weekdays_min
-                    .get_by_index(moment.weekday().num_days_from_sunday())
-                    .unwrap(),
-            )),
-            "ddd" => Some(String::from(
+                    .get_by_index(moment.weekday().num_days_from_sunday()),
+                "weekday",
+            ),
+            "ddd" => missing_name(
                 self.weekdays_short
-                    .get_by_index(moment.weekday().num_days_from_sunday())
-                    .unwrap(),
-            )),
-            "dddd" => Some(String::from(
+                    .get_by_index(moment.weekday().num_days_from_sunday()),
+                "weekday",
+            ),
+            "dddd" => missing_name(
                 self.weekdays
-                    .get_by_index(moment.weekday().num_days_from_sunday())
-                    .unwrap(),
-            )),
+                    .get_by_index(moment.weekday().num_days_from_sunday()),
+                "weekday",
+            ),
 
             // Day of Week (Locale)
-            "e" => Some(format!("{}", moment.locale_aware_day_of_week())),
+            "e" => Ok(format!("{}", moment.locale_aware_day_of_week())),
 
             // Day of Week (ISO)
-            "E" => Some(format!("{}", moment.weekday().num_days_from_monday())),
+            "E" => Ok(format!("{}", moment.weekday().num_days_from_monday())),
 
             // Week of Year
-            "w" => Some(format!("{}", moment.iso_week().week())),
-            "wo" => Some((self.ordinal)(moment.iso_week().week() as i32)),
-            "ww" => Some(format!("{:02}", moment.iso_week().week())),
+            "w" => Ok(format!("{}", moment.iso_week().week())),
+            "wo" => Ok(self.ordinal.format(moment.iso_week().week() as i32)),
+            "ww" => Ok(format!("{:02}", moment.iso_week().week())),
 
             // Year
-            "YY" => Some(format!("{:02}", moment.year_ce().1 % 100)),
-            "YYYY" => Some(format!("{:04}", moment.year_ce().1)),
+            "YY" => Ok(format!("{:02}", moment.year_ce().1 % 100)),
+            "YYYY" => Ok(format!("{:04}", moment.year_ce().1)),
             "Y" => {
                 let (positive, year) = moment.year_ce();
-                Some(format!("{:04}", year as i32 * positive as i32))
+                Ok(format!("{:04}", year as i32 * positive as i32))
             }
 
             // Week Year
-            "gg" => Some(format!("{:02}", moment.locale_aware_week_of_year().1 % 100)),
-            "gggg" => Some(format!("{:04}", moment.locale_aware_week_of_year().1)),
+            "gg" => Ok(format!("{:02}", moment.locale_aware_week_of_year().1 % 100)),
+            "gggg" => Ok(format!("{:04}", moment.locale_aware_week_of_year().1)),
 
             // Week Year (ISO)
-            "GG" => Some(format!("{:02}", moment.iso_week().year() % 100)),
-            "GGGG" => Some(format!("{:04}", moment.iso_week().year())),
+            "GG" => Ok(format!("{:02}", moment.iso_week().year() % 100)),
+            "GGGG" => Ok(format!("{:04}", moment.iso_week().year())),
 
             // AM/PM
-            "A" => Some((self.meridiem)(moment.hour(), moment.minute())),
-            "a" => Some((self.meridiem)(moment.hour(), moment.minute()).to_lowercase()),
+            "A" => Ok(String::from(
+                self.meridiem.format(moment.hour(), moment.minute()),
+            )),
+            "a" => Ok(self
+                .meridiem
+                .format(moment.hour(), moment.minute())
+                .to_lowercase()),
 
             // Hour
-            "H" => Some(format!("{}", moment.hour())),
-            "HH" => Some(format!("{:02}", moment.hour())),
-            "h" => Some(format!("{}", moment.hour12().1)),
-            "hh" => Some(format!("{:02}", moment.hour12().1)),
-            "k" => Some(format!("{}", moment.hour() + 1)),
-            "kk" => Some(format!("{:02}", moment.hour() + 1)),
+            "H" => Ok(format!("{}", moment.hour())),
+            "HH" => Ok(format!("{:02}", moment.hour())),
+            "h" => Ok(format!("{}", moment.hour12().1)),
+            "hh" => Ok(format!("{:02}", moment.hour12().1)),
+            "k" => Ok(format!(
+                "{}",
+                if moment.hour() == 0 {
+                    24
+                } else {
+                    moment.hour()
+                }
+            )),
+            "kk" => Ok(format!(
+                "{:02}",
+                if moment.hour() == 0 {
+                    24
+                } else {
+                    moment.hour()
+                }
+            )),
 
             // Minute
-            "m" => Some(format!("{}", moment.minute())),
-            "mm" => Some(format!("{:02}", moment.minute())),
+            "m" => Ok(format!("{}", moment.minute())),
+            "mm" => Ok(format!("{:02}", moment.minute())),
 
             // Second
-            "s" => Some(format!("{}", moment.second())),
-            "ss" => Some(format!("{:02}", moment.second())),
+            "s" => Ok(format!("{}", moment.second())),
+            "ss" => Ok(format!("{:02}", moment.second())),
 
             // Fractional Second
-            "S" => Some(format!("{}", moment.nanosecond() / 100_000_000)),
-            "SS" => Some(format!("{:02}", moment.nanosecond() / 10_000_000)),
-            "SSS" => Some(format!("{:03}", moment.nanosecond() / 1_000_000)),
-            "SSSS" => Some(format!("{:04}", moment.nanosecond() / 100_000)),
-            "SSSSS" => Some(format!("{:05}", moment.nanosecond() / 10000)),
-            "SSSSSS" => Some(format!("{:06}", moment.nanosecond() / 1000)),
-            "SSSSSSS" => Some(format!("{:06}", moment.nanosecond() / 100)),
-            "SSSSSSSS" => Some(format!("{:08}", moment.nanosecond() / 10)),
-            "SSSSSSSSS" => Some(format!("{:09}", moment.nanosecond())),
+            "S" => Ok(format!("{}", moment.nanosecond() / 100_000_000)),
+            "SS" => Ok(format!("{:02}", moment.nanosecond() / 10_000_000)),
+            "SSS" => Ok(format!("{:03}", moment.nanosecond() / 1_000_000)),
+            "SSSS" => Ok(format!("{:04}", moment.nanosecond() / 100_000)),
+            "SSSSS" => Ok(format!("{:05}", moment.nanosecond() / 10000)),
+            "SSSSSS" => Ok(format!("{:06}", moment.nanosecond() / 1000)),
+            "SSSSSSS" => Ok(format!("{:07}", moment.nanosecond() / 100)),
+            "SSSSSSSS" => Ok(format!("{:08}", moment.nanosecond() / 10)),
+            "SSSSSSSSS" => Ok(format!("{:09}", moment.nanosecond())),
 
             // Time Zone
-            // TODO: Find a way to do timezones names.
-            "z" => unimplemented!("Timezones are difficult..."),
             "Z" => {
-                let offset = moment.offset().local_minus_utc();
+                let offset = moment.offset().fix().local_minus_utc();
                 let (sign, offset) = if offset < 0 {
                     ('-', -offset)
                 } else {
@@ -195,10 +260,10 @@ impl Locale {
                 };
                 let (mins, ..) = div_mod_floor(offset, 60);
                 let (hour, min) = div_mod_floor(mins, 60);
-                Some(format!("{}{:02}:{:02}", sign, hour, min))
+                Ok(format!("{}{:02}:{:02}", sign, hour, min))
             }
             "ZZ" => {
-                let offset = moment.offset().local_minus_utc();
+                let offset = moment.offset().fix().local_minus_utc();
                 let (sign, offset) = if offset < 0 {
                     ('-', -offset)
                 } else {
@@ -206,36 +271,120 @@ impl Locale {
                 };
                 let (mins, ..) = div_mod_floor(offset, 60);
                 let (hour, min) = div_mod_floor(mins, 60);
-                Some(format!("{}{:02}{:02}", sign, hour, min))
+                Ok(format!("{}{:02}{:02}", sign, hour, min))
+            }
+            "z" => Ok(moment.offset().abbreviation().into_owned()),
+            "zz" => {
+                let offset = moment.offset();
+                Ok(offset
+                    .zone_name()
+                    .and_then(|zone| self.time_zone_names.get(zone, offset.is_dst()))
+                    .map_or_else(|| offset.abbreviation().into_owned(), String::from))
             }
 
             // Unix Timestamp
-            "X" => Some(format!("{}", moment.timestamp())),
+            "X" => Ok(format!("{}", moment.timestamp())),
 
             // Unix Millisecond Timestamp
-            "x" => Some(format!("{}", moment.timestamp_millis())),
+            "x" => Ok(format!("{}", moment.timestamp_millis())),
 
-            _ => None,
+            _ => Err(MomentError::UnsupportedToken(String::from(token))),
         }
     }
 
-    pub fn format<T: TimeZone + Debug>(&self, moment: &Moment<T>, format: String) -> String {
-        let expanded_format = self.expand_format(format);
-
-        let formatted_string =
-            FORMATTING_TOKENS.replace_all(&expanded_format, |captures: &Captures| {
-                let mut replacement_value = String::from("");
-                if let Some(match_text) = captures.get(0) {
-                    let input = match_text.as_str();
-                    let value = self
-                        .format_tokens(input, &moment)
-                        .unwrap_or_else(|| self.remove_formatting_tokens(input));
-                    replacement_value = format!("{}{}", replacement_value, value);
-                }
-                replacement_value
+    /// Expands the locale's long date formats in `format` and splits it into tokens, so that it
+    /// can format many moments without being parsed again.
+    pub fn compile_format(&self, format: &str) -> CompiledFormat {
+        let expanded_format = self.expand_format(String::from(format));
+        let mut pieces = vec![];
+        let mut last_end = 0;
+
+        for captures in FORMATTING_TOKENS.captures_iter(&expanded_format) {
+            let match_text = captures.get(0).unwrap();
+            if last_end < match_text.start() {
+                pieces.push(FormatPiece::Literal(String::from(
+                    &expanded_format[last_end..match_text.start()],
+                )));
+            }
+            last_end = match_text.end();
+            let is_escaped = captures.get(1).is_some() || captures.get(2).is_some();
+            pieces.push(if is_escaped || !TOKEN.is_match(match_text.as_str()) {
+                FormatPiece::Literal(self.remove_formatting_tokens(match_text.as_str()))
+            } else {
+                FormatPiece::Token(String::from(match_text.as_str()))
             });
+        }
+        if last_end < expanded_format.len() {
+            pieces.push(FormatPiece::Literal(String::from(
+                &expanded_format[last_end..],
+            )));
+        }
+
+        CompiledFormat { pieces }
+    }
+
+    /// Formats with a format string from [`Locale::compile_format`]. Tokens that cannot be
+    /// formatted are written as they are.
+    pub fn format_compiled<T: TimeZone + Debug>(
+        &self,
+        moment: &Moment<T>,
+        format: &CompiledFormat,
+    ) -> String
+    where
+        T::Offset: OffsetName,
+    {
+        let mut formatted_string = String::new();
+        for piece in format.pieces.iter() {
+            match piece {
+                FormatPiece::Literal(text) => formatted_string.push_str(text),
+                FormatPiece::Token(token) => formatted_string.push_str(
+                    &self
+                        .format_tokens(token, moment)
+                        .unwrap_or_else(|_| token.clone()),
+                ),
+            }
+        }
+        formatted_string
+    }
+
+    /// Like [`Locale::format_compiled`], but fails on the first token that cannot be formatted,
+    /// such as `W`, or whose name is missing from the locale.
+    pub fn checked_format_compiled<T: TimeZone + Debug>(
+        &self,
+        moment: &Moment<T>,
+        format: &CompiledFormat,
+    ) -> Result<String, MomentError>
+    where
+        T::Offset: OffsetName,
+    {
+        let mut formatted_string = String::new();
+        for piece in format.pieces.iter() {
+            match piece {
+                FormatPiece::Literal(text) => formatted_string.push_str(text),
+                FormatPiece::Token(token) => {
+                    formatted_string.push_str(&self.format_tokens(token, moment)?)
+                }
+            }
+        }
+        Ok(formatted_string)
+    }
 
-        String::from(formatted_string)
+    pub fn checked_format<T: TimeZone + Debug>(
+        &self,
+        moment: &Moment<T>,
+        format: String,
+    ) -> Result<String, MomentError>
+    where
+        T::Offset: OffsetName,
+    {
+        self.checked_format_compiled(moment, &self.compile_format(&format))
+    }
+
+    pub fn format<T: TimeZone + Debug>(&self, moment: &Moment<T>, format: String) -> String
+    where
+        T::Offset: OffsetName,
+    {
+        self.format_compiled(moment, &self.compile_format(&format))
     }
 
     pub fn calendar<T: TimeZone + Debug>(
@@ -244,7 +393,10 @@ impl Locale {
         reference_moment: Moment<T>,
         formats: Option<&Calendar>,
         calendar_format: Option<CalendarFormatFn<T>>,
-    ) -> String {
+    ) -> String
+    where
+        T::Offset: OffsetName,
+    {
         let calendar_format_fn = calendar_format.unwrap_or(default_calendar_format);
         let calendar_format =
             calendar_format_fn(moment, &reference_moment.start_of(UnitOfTime::Day));
@@ -255,29 +407,40 @@ impl Locale {
     }
 }
 
+fn missing_name(name: Option<&str>, kind: &str) -> Result<String, MomentError> {
+    name.map(String::from)
+        .ok_or_else(|| MomentError::InvalidLocale(format!("a {} name is missing", kind)))
+}
+
 pub type CalendarFormatFn<T> = fn(&Moment<T>, &Moment<T>) -> CalendarFormat;
 
 pub fn default_calendar_format<T: TimeZone + Debug>(
     moment: &Moment<T>,
     reference_moment: &Moment<T>,
 ) -> CalendarFormat {
-    let diff = moment.signed_duration_since(**reference_moment).num_days();
-    if diff < -6 {
+    // As in Moment.js, the difference is in fractional wall-clock days, so that any time before
+    // the start of the reference day is in the past.
+    let diff = moment
+        .naive_local()
+        .signed_duration_since(reference_moment.naive_local())
+        .num_milliseconds() as f64
+        / 86_400_000_f64;
+    if diff < -6_f64 {
         return CalendarFormat::SameElse;
     }
-    if diff < -1 {
+    if diff < -1_f64 {
         return CalendarFormat::LastWeek;
     }
-    if diff < 0 {
+    if diff < 0_f64 {
         return CalendarFormat::LastDay;
     }
-    if diff < 1 {
+    if diff < 1_f64 {
         return CalendarFormat::SameDay;
     }
-    if diff < 2 {
+    if diff < 2_f64 {
         return CalendarFormat::NextDay;
     }
-    if diff < 7 {
+    if diff < 7_f64 {
         return CalendarFormat::NextWeek;
     }
     CalendarFormat::SameElse
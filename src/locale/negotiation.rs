@@ -0,0 +1,171 @@
+use super::{default_locale, registry::get_locale, Locale};
+use std::{env, fmt, sync::Arc};
+
+/// The language, script and region subtags of a BCP 47 language tag. Variants, extensions and
+/// private use subtags are accepted but not kept, since locales are not registered by them.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LanguageTag {
+    language: String,
+    script: Option<String>,
+    region: Option<String>,
+}
+
+impl LanguageTag {
+    /// Parses a tag such as `zh-Hant-TW`. Underscores are read as hyphens, so POSIX locale names
+    /// such as `en_GB` are accepted as well.
+    pub fn parse(tag: &str) -> Option<LanguageTag> {
+        let tag = tag.trim().replace('_', "-");
+        let mut subtags = tag.split('-');
+        let language = subtags.next()?;
+        if !(2..=8).contains(&language.len())
+            || language.len() == 4
+            || !language.chars().all(|c| c.is_ascii_alphabetic())
+        {
+            return None;
+        }
+        let mut language_tag = LanguageTag {
+            language: language.to_lowercase(),
+            script: None,
+            region: None,
+        };
+        for subtag in subtags {
+            if subtag.is_empty()
+                || subtag.len() > 8
+                || !subtag.chars().all(|c| c.is_ascii_alphanumeric())
+            {
+                return None;
+            }
+            if subtag.len() == 1 {
+                break;
+            }
+            let alphabetic = subtag.chars().all(|c| c.is_ascii_alphabetic());
+            let numeric = subtag.chars().all(|c| c.is_ascii_digit());
+            if language_tag.region.is_some() {
+                continue;
+            }
+            if subtag.len() == 4 && alphabetic && language_tag.script.is_none() {
+                let (first, rest) = subtag.split_at(1);
+                language_tag.script = Some(first.to_uppercase() + &rest.to_lowercase());
+            } else if (subtag.len() == 2 && alphabetic) || (subtag.len() == 3 && numeric) {
+                language_tag.region = Some(subtag.to_uppercase());
+            }
+        }
+        Some(language_tag)
+    }
+
+    pub fn language(&self) -> &str {
+        &self.language
+    }
+
+    pub fn script(&self) -> Option<&str> {
+        self.script.as_deref()
+    }
+
+    pub fn region(&self) -> Option<&str> {
+        self.region.as_deref()
+    }
+
+    // The tags to look up, most specific first: `zh-Hant-TW`, `zh-Hant`, `zh-TW`, then `zh`.
+    fn fallbacks(&self) -> Vec<String> {
+        let mut fallbacks = Vec::new();
+        if let Some(script) = &self.script {
+            if let Some(region) = &self.region {
+                fallbacks.push(format!("{}-{}-{}", self.language, script, region));
+            }
+            fallbacks.push(format!("{}-{}", self.language, script));
+        }
+        if let Some(region) = &self.region {
+            fallbacks.push(format!("{}-{}", self.language, region));
+        }
+        fallbacks.push(self.language.clone());
+        fallbacks
+    }
+}
+
+impl fmt::Display for LanguageTag {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.language)?;
+        if let Some(script) = &self.script {
+            write!(f, "-{}", script)?;
+        }
+        if let Some(region) = &self.region {
+            write!(f, "-{}", region)?;
+        }
+        Ok(())
+    }
+}
+
+/// The result of locale negotiation: the registered locale that was chosen and the preferred tag
+/// that selected it, such as `fr-CA` when it fell back to a locale registered as `fr`.
+#[derive(Debug, Clone)]
+pub struct LocaleMatch {
+    pub locale: Arc<Locale>,
+    pub tag: String,
+}
+
+/// Chooses the registered locale for the first tag in `preferences` that has one, falling back
+/// from `en-GB` to `en` for each tag before trying the next. `*` matches the default locale.
+pub fn negotiate_locale<S: AsRef<str>>(preferences: &[S]) -> Option<LocaleMatch> {
+    preferences.iter().find_map(|preference| {
+        let preference = preference.as_ref().trim();
+        if preference == "*" {
+            return Some(LocaleMatch {
+                locale: default_locale(),
+                tag: String::from("*"),
+            });
+        }
+        let language_tag = LanguageTag::parse(preference)?;
+        language_tag
+            .fallbacks()
+            .iter()
+            .find_map(|fallback| get_locale(fallback))
+            .map(|locale| LocaleMatch {
+                locale,
+                tag: language_tag.to_string(),
+            })
+    })
+}
+
+/// Chooses a locale for an HTTP `Accept-Language` header such as `fr-CA,fr;q=0.9,en;q=0.8`.
+/// Tags are tried in order of quality, and tags with a quality of 0 or one that cannot be read
+/// are ignored.
+pub fn negotiate_accept_language(accept_language: &str) -> Option<LocaleMatch> {
+    let mut preferences: Vec<(&str, f32)> = accept_language
+        .split(',')
+        .filter_map(|range| {
+            let mut parameters = range.split(';');
+            let tag = parameters.next()?.trim();
+            let mut quality = 1.0;
+            for parameter in parameters {
+                let mut key_value = parameter.splitn(2, '=');
+                let key = key_value.next()?.trim();
+                if key.eq_ignore_ascii_case("q") {
+                    quality = key_value.next()?.trim().parse().ok()?;
+                }
+            }
+            if tag.is_empty() || !(quality > 0.0 && quality <= 1.0) {
+                return None;
+            }
+            Some((tag, quality))
+        })
+        .collect();
+    // A stable sort keeps tags with the same quality in the order they were listed.
+    preferences.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap());
+    let tags: Vec<&str> = preferences.into_iter().map(|(tag, _)| tag).collect();
+    negotiate_locale(&tags)
+}
+
+/// Chooses a locale from the first of the `LC_ALL`, `LC_TIME` and `LANG` environment variables
+/// that is set, as the C library does. The codeset and modifier of names such as
+/// `en_GB.UTF-8@euro` are ignored, and the `C` and `POSIX` locales have no match.
+pub fn negotiate_env_locale() -> Option<LocaleMatch> {
+    let name = ["LC_ALL", "LC_TIME", "LANG"]
+        .iter()
+        .filter_map(|variable| env::var(variable).ok())
+        .find(|value| !value.is_empty())?;
+    let name = name.split(['.', '@']).next().unwrap_or("");
+    if name == "C" || name == "POSIX" {
+        return None;
+    }
+    negotiate_locale(&[name])
+}
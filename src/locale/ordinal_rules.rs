@@ -0,0 +1,38 @@
+use serde::Deserialize;
+use std::borrow::Cow;
+
+/// One case of [`Ordinal`]. The rule matches when `values` contains the number, or the number
+/// modulo `modulo` when it is set.
+#[derive(Debug, Clone, Deserialize)]
+pub struct OrdinalRule {
+    #[serde(default)]
+    pub modulo: Option<i32>,
+    pub values: Vec<i32>,
+    pub format: Cow<'static, str>,
+}
+
+/// How numbers are written as ordinals by tokens such as `Do`. The format of the first matching
+/// rule is used, or `default` when none match, with `%d` replaced by the number.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Ordinal {
+    #[serde(default)]
+    pub rules: Vec<OrdinalRule>,
+    pub default: Cow<'static, str>,
+}
+
+impl Ordinal {
+    pub fn format(&self, number: i32) -> String {
+        let format = self
+            .rules
+            .iter()
+            .find(|rule| {
+                let value = match rule.modulo {
+                    Some(modulo) if modulo != 0 => number.abs() % modulo,
+                    _ => number,
+                };
+                rule.values.contains(&value)
+            })
+            .map_or(&self.default, |rule| &rule.format);
+        format.replacen("%d", &number.to_string(), 1)
+    }
+}
@@ -0,0 +1,562 @@
+use super::{Locale, MonthStrings, WeekDayStrings, FORMATTING_TOKENS};
+use crate::{
+    tz::{from_local, with_time_zone_abbreviations, Disambiguation},
+    MomentError,
+};
+use chrono::prelude::*;
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::{collections::HashMap, sync::RwLock};
+
+lazy_static! {
+    static ref MATCH_1: Regex = Regex::new(r"\d").unwrap();
+    static ref MATCH_2: Regex = Regex::new(r"\d\d").unwrap();
+    static ref MATCH_3: Regex = Regex::new(r"\d{3}").unwrap();
+    static ref MATCH_4: Regex = Regex::new(r"\d{4}").unwrap();
+    static ref MATCH_6: Regex = Regex::new(r"[+-]?\d{6}").unwrap();
+    static ref MATCH_1_TO_2: Regex = Regex::new(r"\d\d?").unwrap();
+    static ref MATCH_1_TO_3: Regex = Regex::new(r"\d{1,3}").unwrap();
+    static ref MATCH_1_TO_4: Regex = Regex::new(r"\d{1,4}").unwrap();
+    static ref MATCH_1_TO_6: Regex = Regex::new(r"[+-]?\d{1,6}").unwrap();
+    static ref MATCH_3_TO_4: Regex = Regex::new(r"\d\d\d\d?").unwrap();
+    static ref MATCH_5_TO_6: Regex = Regex::new(r"\d\d\d\d\d\d?").unwrap();
+    static ref MATCH_UNSIGNED: Regex = Regex::new(r"\d+").unwrap();
+    static ref MATCH_SIGNED: Regex = Regex::new(r"[+-]?\d+").unwrap();
+    static ref MATCH_SHORT_OFFSET: Regex = Regex::new(r"(?i)Z|[+-]\d\d(?::?\d\d)?").unwrap();
+    static ref MATCH_TIMESTAMP: Regex = Regex::new(r"[+-]?\d+(\.\d{1,3})?").unwrap();
+    // Exactly 4 to 9 digits, for the `SSSS` to `SSSSSSSSS` tokens in strict mode.
+    static ref MATCH_FRACTIONS: Vec<Regex> = (4..=9)
+        .map(|length| Regex::new(&format!(r"\d{{{}}}", length)).unwrap())
+        .collect();
+    // Regexes built from locale data or the time zone abbreviations, keyed by pattern, so that
+    // each one is only compiled once.
+    static ref REGEX_CACHE: RwLock<HashMap<String, Regex>> = RwLock::new(HashMap::new());
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum ParseMode {
+    /// Matches each token against the first occurrence of its pattern in the remaining input,
+    /// skipping mismatched separators and trailing text, as Moment.js does by default.
+    Forgiving,
+    /// Requires the input to match the format exactly, with fixed-width numeric tokens.
+    Strict,
+}
+
+#[derive(Debug, Clone)]
+enum ParseToken {
+    Literal(String),
+    Token(String, Regex),
+}
+
+#[derive(Debug, Default)]
+struct ParsedDate {
+    year: Option<i32>,
+    month: Option<u32>,
+    day: Option<u32>,
+    day_of_year: Option<u32>,
+    hour: Option<u32>,
+    minute: Option<u32>,
+    second: Option<u32>,
+    nanosecond: Option<u32>,
+    weekday: Option<u32>,
+    is_pm: Option<bool>,
+    offset: Option<i32>,
+    timestamp_millis: Option<i64>,
+}
+
+fn cached_regex(pattern: String) -> Result<Regex, regex::Error> {
+    if let Some(regex) = REGEX_CACHE.read().unwrap().get(&pattern) {
+        return Ok(regex.clone());
+    }
+    let regex = Regex::new(&pattern)?;
+    REGEX_CACHE.write().unwrap().insert(pattern, regex.clone());
+    Ok(regex)
+}
+
+fn names_pattern(names: &[&str]) -> String {
+    // Longer names go first so that "June" is not matched as "Jun" followed by "e".
+    let mut names = names.to_vec();
+    names.sort_by_key(|name| std::cmp::Reverse(name.len()));
+    let alternatives: Vec<String> = names.iter().map(|name| regex::escape(name)).collect();
+    format!("(?i){}", alternatives.join("|"))
+}
+
+fn names_regex(names: &[&str]) -> Regex {
+    cached_regex(names_pattern(names)).unwrap()
+}
+
+fn find_name_index(names: &[&str], input: &str) -> Option<u32> {
+    names
+        .iter()
+        .position(|name| name.to_lowercase() == input.to_lowercase())
+        .map(|index| index as u32)
+}
+
+fn parse_number<T: std::str::FromStr>(input: &str) -> Option<T> {
+    input.parse::<T>().ok()
+}
+
+fn parse_two_digit_year(input: i32) -> i32 {
+    input + if input > 68 { 1900 } else { 2000 }
+}
+
+/// Reads the last offset like `+05:30`, `-0800` or `Z` in `input` as seconds east of UTC, the
+/// same way Moment.js's `utcOffset` reads strings.
+pub(crate) fn parse_utc_offset(input: &str) -> Option<i32> {
+    parse_offset(MATCH_SHORT_OFFSET.find_iter(input).last()?.as_str())
+}
+
+fn parse_offset(input: &str) -> Option<i32> {
+    if input.eq_ignore_ascii_case("z") {
+        return Some(0);
+    }
+    let sign = if input.starts_with('-') { -1 } else { 1 };
+    let digits = input[1..].replace(':', "");
+    let hours = parse_number::<i32>(&digits[..2])?;
+    let minutes = if digits.len() > 2 {
+        parse_number::<i32>(&digits[2..])?
+    } else {
+        0
+    };
+    Some(sign * (hours * 3600 + minutes * 60))
+}
+
+fn parse_fraction(input: &str) -> Option<u32> {
+    let digits: String = input.chars().chain("000000000".chars()).take(9).collect();
+    parse_number(&digits)
+}
+
+impl ParsedDate {
+    fn apply(&mut self, locale: &Locale, token: &str, input: &str) -> Option<()> {
+        match token {
+            "M" | "MM" => self.month = Some(parse_number(input)?),
+            "MMM" | "MMMM" => {
+                let index = find_name_index(&locale.all_month_names(), input)?;
+                self.month = Some(index % 12 + 1);
+            }
+            "Q" => self.month = Some((parse_number::<u32>(input)?.max(1) - 1) * 3 + 1),
+            "D" | "DD" => self.day = Some(parse_number(input)?),
+            "Do" => {
+                let digits: String = input.chars().take_while(char::is_ascii_digit).collect();
+                self.day = Some(parse_number(&digits)?);
+            }
+            "dd" | "ddd" | "dddd" => {
+                let index = find_name_index(&locale.all_weekday_names(), input)?;
+                self.weekday = Some(index % 7);
+            }
+            "a" | "A" => self.is_pm = Some(locale.pm_parse.is_match(input)),
+            "DDD" | "DDDD" => self.day_of_year = Some(parse_number(input)?),
+            "YY" => self.year = Some(parse_two_digit_year(parse_number(input)?)),
+            "YYYY" if input.len() == 2 => {
+                self.year = Some(parse_two_digit_year(parse_number(input)?))
+            }
+            "Y" | "YYYY" | "YYYYY" | "YYYYYY" => self.year = Some(parse_number(input)?),
+            "H" | "HH" | "h" | "hh" => self.hour = Some(parse_number(input)?),
+            "k" | "kk" => self.hour = Some(parse_number::<u32>(input)? % 24),
+            "m" | "mm" => self.minute = Some(parse_number(input)?),
+            "s" | "ss" => self.second = Some(parse_number(input)?),
+            "Hmm" | "hmm" | "Hmmss" | "hmmss" => {
+                let (hour, rest) = input.split_at(input.len() - token.len() + 1);
+                let (minute, second) = rest.split_at(2);
+                self.hour = Some(parse_number(hour)?);
+                self.minute = Some(parse_number(minute)?);
+                if !second.is_empty() {
+                    self.second = Some(parse_number(second)?);
+                }
+            }
+            "Z" | "ZZ" => self.offset = Some(parse_offset(input)?),
+            "z" => {
+                self.offset = Some(with_time_zone_abbreviations(|abbreviations| {
+                    abbreviations.get(input)
+                })?)
+            }
+            "X" => {
+                let seconds = parse_number::<f64>(input)?;
+                self.timestamp_millis = Some((seconds * 1000_f64).round() as i64);
+            }
+            "x" => self.timestamp_millis = Some(parse_number(input)?),
+            _ if token.starts_with('S') => self.nanosecond = Some(parse_fraction(input)?),
+            _ => {}
+        }
+        Some(())
+    }
+
+    fn fixed_offset(&self) -> Result<FixedOffset, MomentError> {
+        let offset_seconds = self.offset.unwrap_or(0);
+        FixedOffset::east_opt(offset_seconds).ok_or(MomentError::OutOfRange {
+            component: "offset",
+            value: i64::from(offset_seconds),
+        })
+    }
+
+    /// Builds the date in `time_zone`. A parsed offset or timestamp fixes the instant, which is
+    /// then converted to `time_zone`; otherwise the parsed wall-clock time is read in `time_zone`
+    /// using `disambiguation`.
+    fn into_date_time<Z: TimeZone>(
+        self,
+        time_zone: &Z,
+        disambiguation: Disambiguation,
+    ) -> Result<DateTime<Z>, MomentError> {
+        let offset = self.fixed_offset()?;
+
+        if let Some(timestamp_millis) = self.timestamp_millis {
+            return Utc
+                .timestamp_millis_opt(timestamp_millis)
+                .single()
+                .map(|date_time| date_time.with_timezone(time_zone))
+                .ok_or(MomentError::OutOfRange {
+                    component: "timestamp",
+                    value: timestamp_millis,
+                });
+        }
+
+        // Like Moment.js, leading date components that were not parsed are taken from the
+        // current date, while any that follow a parsed component default to their minimum.
+        let now = Utc::now().with_timezone(time_zone);
+        let year = self.year.unwrap_or_else(|| now.year());
+        if year < NaiveDate::MIN.year() || year > NaiveDate::MAX.year() {
+            return Err(MomentError::OutOfRange {
+                component: "year",
+                value: i64::from(year),
+            });
+        }
+
+        let date = match self.day_of_year {
+            Some(day_of_year) => {
+                NaiveDate::from_yo_opt(year, day_of_year).ok_or(MomentError::OutOfRange {
+                    component: "day of year",
+                    value: i64::from(day_of_year),
+                })?
+            }
+            None => {
+                let (month, day) = match (self.year, self.month, self.day) {
+                    (None, None, None) => (now.month(), now.day()),
+                    (None, None, Some(day)) => (now.month(), day),
+                    (_, month, day) => (month.unwrap_or(1), day.unwrap_or(1)),
+                };
+                if !(1..=12).contains(&month) {
+                    return Err(MomentError::OutOfRange {
+                        component: "month",
+                        value: i64::from(month),
+                    });
+                }
+                NaiveDate::from_ymd_opt(year, month, day).ok_or(MomentError::OutOfRange {
+                    component: "day",
+                    value: i64::from(day),
+                })?
+            }
+        };
+
+        if let Some(weekday) = self.weekday {
+            if date.weekday().num_days_from_sunday() != weekday {
+                return Err(MomentError::OutOfRange {
+                    component: "weekday",
+                    value: i64::from(weekday),
+                });
+            }
+        }
+
+        let hour = match (self.is_pm, self.hour) {
+            (Some(true), Some(hour)) if hour < 12 => hour + 12,
+            (Some(false), Some(12)) => 0,
+            (_, hour) => hour.unwrap_or(0),
+        };
+        let minute = self.minute.unwrap_or(0);
+        let second = self.second.unwrap_or(0);
+        for (component, value, max) in [
+            ("hour", hour, 23),
+            ("minute", minute, 59),
+            ("second", second, 59),
+        ]
+        .iter()
+        {
+            if value > max {
+                return Err(MomentError::OutOfRange {
+                    component,
+                    value: i64::from(*value),
+                });
+            }
+        }
+
+        let time = NaiveTime::from_hms_nano_opt(hour, minute, second, self.nanosecond.unwrap_or(0))
+            .ok_or(MomentError::OutOfRange {
+                component: "nanosecond",
+                value: i64::from(self.nanosecond.unwrap_or(0)),
+            })?;
+
+        let local = date.and_time(time);
+        match self.offset {
+            Some(_) => offset
+                .from_local_datetime(&local)
+                .single()
+                .map(|date_time| date_time.with_timezone(time_zone))
+                .ok_or(MomentError::Overflow),
+            None => from_local(time_zone, &local, disambiguation),
+        }
+    }
+}
+
+impl Locale {
+    fn month_names<'a>(&self, strings: &[&'a MonthStrings]) -> Vec<&'a str> {
+        strings
+            .iter()
+            .flat_map(|strings| (0..12).filter_map(move |index| strings.get_index(index)))
+            .collect()
+    }
+
+    fn weekday_names<'a>(&self, strings: &[&'a WeekDayStrings]) -> Vec<&'a str> {
+        strings
+            .iter()
+            .flat_map(|strings| (0..7).filter_map(move |index| strings.get_by_index(index)))
+            .collect()
+    }
+
+    fn all_month_names(&self) -> Vec<&str> {
+        self.month_names(&[&self.months, &self.months_short])
+    }
+
+    fn all_weekday_names(&self) -> Vec<&str> {
+        self.weekday_names(&[&self.weekdays, &self.weekdays_short, &self.weekdays_min])
+    }
+
+    fn strict_parse_regex_for_token(&self, token: &str) -> Option<Regex> {
+        let regex: &Regex = match token {
+            "MM" | "DD" | "YY" | "HH" | "hh" | "kk" | "mm" | "ss" | "SS" => &MATCH_2,
+            "DDDD" | "SSS" => &MATCH_3,
+            "YYYY" => &MATCH_4,
+            "YYYYY" | "YYYYYY" => &MATCH_6,
+            "S" => &MATCH_1,
+            "SSSS" | "SSSSS" | "SSSSSS" | "SSSSSSS" | "SSSSSSSS" | "SSSSSSSSS" => {
+                &MATCH_FRACTIONS[token.len() - 4]
+            }
+            "MMMM" => return Some(names_regex(&self.month_names(&[&self.months]))),
+            "MMM" => return Some(names_regex(&self.month_names(&[&self.months_short]))),
+            "dddd" => return Some(names_regex(&self.weekday_names(&[&self.weekdays]))),
+            "ddd" => return Some(names_regex(&self.weekday_names(&[&self.weekdays_short]))),
+            "dd" => return Some(names_regex(&self.weekday_names(&[&self.weekdays_min]))),
+            _ => return None,
+        };
+        Some(regex.clone())
+    }
+
+    fn case_insensitive_regex(&self, token: &str, regex: &Regex) -> Result<Regex, MomentError> {
+        cached_regex(format!("(?i){}", regex.as_str()))
+            .map_err(|_| MomentError::UnsupportedToken(String::from(token)))
+    }
+
+    fn parse_regex_for_token(&self, token: &str) -> Result<Option<Regex>, MomentError> {
+        let regex = match token {
+            "M" | "MM" | "D" | "DD" | "YY" | "H" | "HH" | "h" | "hh" | "k" | "kk" | "m" | "mm"
+            | "s" | "ss" => MATCH_1_TO_2.clone(),
+            "Q" => MATCH_1.clone(),
+            "DDD" | "DDDD" => MATCH_1_TO_3.clone(),
+            "YYYY" => MATCH_1_TO_4.clone(),
+            "YYYYY" | "YYYYYY" => MATCH_1_TO_6.clone(),
+            "Y" | "x" => MATCH_SIGNED.clone(),
+            "S" | "SS" | "SSS" => MATCH_1_TO_3.clone(),
+            "SSSS" | "SSSSS" | "SSSSSS" | "SSSSSSS" | "SSSSSSSS" | "SSSSSSSSS" => {
+                MATCH_UNSIGNED.clone()
+            }
+            "Hmm" | "hmm" => MATCH_3_TO_4.clone(),
+            "Hmmss" | "hmmss" => MATCH_5_TO_6.clone(),
+            "Z" | "ZZ" => MATCH_SHORT_OFFSET.clone(),
+            "z" => {
+                let pattern = with_time_zone_abbreviations(|abbreviations| {
+                    let names: Vec<&str> = abbreviations.abbreviations().collect();
+                    format!(r"\b(?:{})\b", names_pattern(&names))
+                });
+                cached_regex(pattern)
+                    .map_err(|_| MomentError::UnsupportedToken(String::from(token)))?
+            }
+            "X" => MATCH_TIMESTAMP.clone(),
+            "MMM" | "MMMM" => names_regex(&self.all_month_names()),
+            "dd" | "ddd" | "dddd" => names_regex(&self.all_weekday_names()),
+            "Do" => self.case_insensitive_regex(token, &self.day_of_month_ordinal_parse)?,
+            "a" | "A" => self.case_insensitive_regex(token, &self.meridiem_parse)?,
+            "Mo" | "Qo" | "DDDo" | "d" | "do" | "e" | "E" | "w" | "wo" | "ww" | "W" | "Wo"
+            | "WW" | "gg" | "gggg" | "ggggg" | "GG" | "GGGG" | "GGGGG" | "zz" => {
+                return Err(MomentError::UnsupportedToken(String::from(token)));
+            }
+            _ => return Ok(None),
+        };
+        Ok(Some(regex))
+    }
+
+    fn parse_tokens(&self, format: &str, mode: ParseMode) -> Result<Vec<ParseToken>, MomentError> {
+        let expanded_format = self.expand_format(String::from(format));
+        let mut tokens = vec![];
+        for captures in FORMATTING_TOKENS.captures_iter(&expanded_format) {
+            if let Some(escaped) = captures.get(1) {
+                let text = escaped.as_str();
+                tokens.push(ParseToken::Literal(String::from(&text[1..text.len() - 1])));
+                continue;
+            }
+
+            let token = captures.get(3).map_or("", |token| token.as_str());
+            if captures.get(2).is_some() {
+                tokens.push(ParseToken::Literal(String::from(token)));
+                continue;
+            }
+            match self.parse_regex_for_token(token)? {
+                Some(regex) => {
+                    let regex = match mode {
+                        ParseMode::Strict => self.strict_parse_regex_for_token(token),
+                        ParseMode::Forgiving => None,
+                    }
+                    .unwrap_or(regex);
+                    tokens.push(ParseToken::Token(String::from(token), regex));
+                }
+                None => tokens.push(ParseToken::Literal(String::from(token))),
+            }
+        }
+        Ok(tokens)
+    }
+
+    /// Parses `input` with a single format, returning the date along with a score of how much of
+    /// the input and format went unused (lower is better), as Moment.js does when it picks between
+    /// several formats.
+    fn parse_format(
+        &self,
+        input: &str,
+        format: &str,
+        mode: ParseMode,
+    ) -> Result<(ParsedDate, usize), MomentError> {
+        let parse_error = |offset: usize| MomentError::Parse {
+            input: String::from(input),
+            format: Some(String::from(format)),
+            offset,
+        };
+
+        let tokens = self.parse_tokens(format, mode)?;
+        let mut parsed_date = ParsedDate::default();
+        let mut remaining = input;
+        let mut parsed_length = 0;
+        let mut unused_tokens = 0;
+        let mut first_unparsed: Option<usize> = None;
+        let mut is_empty = true;
+
+        for token in tokens.iter() {
+            let position = input.len() - remaining.len();
+            let found = match token {
+                ParseToken::Literal(text) if text.is_empty() => continue,
+                ParseToken::Literal(text) => remaining
+                    .find(text.as_str())
+                    .map(|index| (index, index + text.len())),
+                ParseToken::Token(token, regex) => {
+                    let found = regex.find(remaining);
+                    if let Some(found) = found {
+                        parsed_date
+                            .apply(self, token.as_str(), found.as_str())
+                            .ok_or_else(|| parse_error(position + found.start()))?;
+                        is_empty = false;
+                    }
+                    found.map(|found| (found.start(), found.end()))
+                }
+            };
+
+            match found {
+                Some((start, end)) => {
+                    if start > 0 {
+                        first_unparsed.get_or_insert(position);
+                    }
+                    remaining = &remaining[end..];
+                    parsed_length += end - start;
+                }
+                None => {
+                    if let ParseToken::Token(..) = token {
+                        unused_tokens += 1;
+                    } else if mode == ParseMode::Strict {
+                        unused_tokens += 1;
+                    }
+                    first_unparsed.get_or_insert(position);
+                }
+            }
+        }
+
+        if !remaining.is_empty() {
+            first_unparsed.get_or_insert(input.len() - remaining.len());
+        }
+
+        let chars_left_over = input.len() - parsed_length;
+        if is_empty {
+            return Err(parse_error(0));
+        }
+        if mode == ParseMode::Strict && (chars_left_over > 0 || unused_tokens > 0) {
+            return Err(parse_error(first_unparsed.unwrap_or(0)));
+        }
+
+        Ok((parsed_date, chars_left_over + unused_tokens * 10))
+    }
+
+    /// Parses `input` with the same tokens that `Locale::format` emits, so that formatting a date
+    /// and parsing the result with the same format string round-trips.
+    ///
+    /// Like Moment.js's default (forgiving) mode, each token is matched against the first
+    /// occurrence of its pattern in the remaining input, so mismatched separators and trailing
+    /// text are skipped. Month and weekday names, ordinals and meridiems are matched
+    /// case-insensitively against this locale's strings.
+    pub fn parse(&self, input: &str, format: &str) -> Result<DateTime<FixedOffset>, MomentError> {
+        let (parsed_date, _) = self.parse_format(input, format, ParseMode::Forgiving)?;
+        let offset = parsed_date.fixed_offset()?;
+        parsed_date.into_date_time(&offset, Disambiguation::Compatible)
+    }
+
+    /// Parses `input` like [`Locale::parse`], but reads the wall-clock time in `time_zone`. If the
+    /// input contains an offset or timestamp, that instant is converted to `time_zone` instead.
+    /// Wall-clock times that `time_zone` skips or repeats are read using `disambiguation`.
+    pub fn parse_in<Z: TimeZone>(
+        &self,
+        input: &str,
+        format: &str,
+        time_zone: &Z,
+        disambiguation: Disambiguation,
+    ) -> Result<DateTime<Z>, MomentError> {
+        let (parsed_date, _) = self.parse_format(input, format, ParseMode::Forgiving)?;
+        parsed_date.into_date_time(time_zone, disambiguation)
+    }
+
+    /// Parses `input` with each of `formats` and returns the best match along with the index of
+    /// the format that produced it. In `ParseMode::Forgiving` the format leaving the fewest unused
+    /// characters and tokens wins; in `ParseMode::Strict` only exact matches are accepted.
+    pub fn parse_formats<F: AsRef<str>>(
+        &self,
+        input: &str,
+        formats: &[F],
+        mode: ParseMode,
+    ) -> Result<(DateTime<FixedOffset>, usize), MomentError> {
+        let mut best_match: Option<(DateTime<FixedOffset>, usize, usize)> = None;
+        let mut first_error = None;
+
+        for (index, format) in formats.iter().enumerate() {
+            let parsed =
+                self.parse_format(input, format.as_ref(), mode)
+                    .and_then(|(parsed_date, score)| {
+                        let offset = parsed_date.fixed_offset()?;
+                        Ok((
+                            parsed_date.into_date_time(&offset, Disambiguation::Compatible)?,
+                            score,
+                        ))
+                    });
+            match parsed {
+                Ok((date_time, score)) => {
+                    if best_match.is_none_or(|(_, _, best_score)| score < best_score) {
+                        best_match = Some((date_time, index, score));
+                    }
+                }
+                Err(error) => {
+                    first_error.get_or_insert(error);
+                }
+            }
+        }
+
+        best_match
+            .map(|(date_time, index, _)| (date_time, index))
+            .ok_or_else(|| {
+                first_error.unwrap_or_else(|| MomentError::Parse {
+                    input: String::from(input),
+                    format: None,
+                    offset: 0,
+                })
+            })
+    }
+}
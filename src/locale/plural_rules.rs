@@ -0,0 +1,297 @@
+use serde::Deserialize;
+use std::borrow::Cow;
+
+/// The CLDR plural categories. Every language uses `Other`; which of the rest it uses depends on
+/// the language.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PluralCategory {
+    Zero,
+    One,
+    Two,
+    Few,
+    Many,
+    Other,
+}
+
+/// The CLDR plural operands of a number: `n` is its absolute value, `i` its integer digits, `v`
+/// the number of visible fraction digits, `f` the fraction digits and `t` the fraction digits
+/// without trailing zeros. `1.50` cannot be told apart from `1.5` in an `f64`, so `v` and `f`
+/// never count trailing zeros.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PluralOperands {
+    pub n: f64,
+    pub i: u64,
+    pub v: u32,
+    pub f: u64,
+    pub t: u64,
+}
+
+impl From<f64> for PluralOperands {
+    fn from(number: f64) -> PluralOperands {
+        let n = number.abs();
+        let text = n.to_string();
+        let fraction = text.split('.').nth(1).unwrap_or("");
+        let f = fraction.parse().unwrap_or(0);
+        PluralOperands {
+            n,
+            i: n.trunc() as u64,
+            v: fraction.len() as u32,
+            f,
+            t: f,
+        }
+    }
+}
+
+impl PluralOperands {
+    // `n` as an integer, when the rule compares it against integers.
+    fn integer(&self) -> Option<u64> {
+        if self.v == 0 {
+            Some(self.i)
+        } else {
+            None
+        }
+    }
+}
+
+/// The CLDR cardinal plural category of `number` in a language, given as a tag such as `ru` or
+/// `pt-PT`. Languages without rules here use the CLDR root rule, where everything is `Other`.
+pub fn plural_category(language: &str, number: f64) -> PluralCategory {
+    use PluralCategory::*;
+
+    let tag = language.replace('_', "-").to_lowercase();
+    let language = tag.split('-').next().unwrap_or("");
+    let operands = PluralOperands::from(number);
+    let PluralOperands { n, i, v, f, t } = operands;
+    let n_integer = operands.integer();
+    let in_range = |value: u64, start: u64, end: u64| value >= start && value <= end;
+    let n_in = |start: u64, end: u64| n_integer.is_some_and(|n| in_range(n, start, end));
+    let n_mod = |modulo: u64| n_integer.map(|n| n % modulo);
+    let millions = v == 0 && i != 0 && i % 1_000_000 == 0;
+
+    match language {
+        "ar" => match n_integer {
+            Some(0) => Zero,
+            Some(1) => One,
+            Some(2) => Two,
+            Some(n) if in_range(n % 100, 3, 10) => Few,
+            Some(n) if in_range(n % 100, 11, 99) => Many,
+            _ => Other,
+        },
+        "be" => match (n_mod(10), n_mod(100)) {
+            (Some(1), Some(m)) if m != 11 => One,
+            (Some(d), Some(m)) if in_range(d, 2, 4) && !in_range(m, 12, 14) => Few,
+            (Some(d), Some(m)) if d == 0 || in_range(d, 5, 9) || in_range(m, 11, 14) => Many,
+            _ => Other,
+        },
+        "bs" | "hr" | "sr" | "sh" => {
+            if (v == 0 && i % 10 == 1 && i % 100 != 11) || (f % 10 == 1 && f % 100 != 11) {
+                One
+            } else if (v == 0 && in_range(i % 10, 2, 4) && !in_range(i % 100, 12, 14))
+                || (in_range(f % 10, 2, 4) && !in_range(f % 100, 12, 14))
+            {
+                Few
+            } else {
+                Other
+            }
+        }
+        "cs" | "sk" => match (i, v) {
+            (1, 0) => One,
+            (2..=4, 0) => Few,
+            (_, 0) => Other,
+            _ => Many,
+        },
+        "cy" => match n_integer {
+            Some(0) => Zero,
+            Some(1) => One,
+            Some(2) => Two,
+            Some(3) => Few,
+            Some(6) => Many,
+            _ => Other,
+        },
+        "da" => {
+            if n_in(1, 1) || (t != 0 && i <= 1) {
+                One
+            } else {
+                Other
+            }
+        }
+        "fr" => {
+            if i <= 1 {
+                One
+            } else if millions {
+                Many
+            } else {
+                Other
+            }
+        }
+        "pt" if tag != "pt-pt" => {
+            if i <= 1 {
+                One
+            } else if millions {
+                Many
+            } else {
+                Other
+            }
+        }
+        "es" => {
+            if n_in(1, 1) {
+                One
+            } else if millions {
+                Many
+            } else {
+                Other
+            }
+        }
+        "ca" | "it" | "pt" => {
+            if i == 1 && v == 0 {
+                One
+            } else if millions {
+                Many
+            } else {
+                Other
+            }
+        }
+        "ga" => match n_integer {
+            Some(1) => One,
+            Some(2) => Two,
+            Some(3..=6) => Few,
+            Some(7..=10) => Many,
+            _ => Other,
+        },
+        "he" | "iw" => match (i, v) {
+            (1, 0) => One,
+            (0, v) if v != 0 => One,
+            (2, 0) => Two,
+            _ => Other,
+        },
+        "am" | "bn" | "fa" | "gu" | "hi" | "kn" | "zu" => {
+            if i == 0 || n_in(1, 1) {
+                One
+            } else {
+                Other
+            }
+        }
+        "is" => {
+            if (t == 0 && i % 10 == 1 && i % 100 != 11) || (t % 10 == 1 && t % 100 != 11) {
+                One
+            } else {
+                Other
+            }
+        }
+        "lt" => match (n_mod(10), n_mod(100)) {
+            (Some(1), Some(m)) if !in_range(m, 11, 19) => One,
+            (Some(d), Some(m)) if in_range(d, 2, 9) && !in_range(m, 11, 19) => Few,
+            _ if f != 0 => Many,
+            _ => Other,
+        },
+        "lv" => {
+            if n_mod(10) == Some(0)
+                || n_mod(100).is_some_and(|m| in_range(m, 11, 19))
+                || (v == 2 && in_range(f % 100, 11, 19))
+            {
+                Zero
+            } else if (n_mod(10) == Some(1) && n_mod(100) != Some(11))
+                || (f % 10 == 1 && (v != 2 || f % 100 != 11))
+            {
+                One
+            } else {
+                Other
+            }
+        }
+        "mk" => {
+            if (v == 0 && i % 10 == 1 && i % 100 != 11) || (f % 10 == 1 && f % 100 != 11) {
+                One
+            } else {
+                Other
+            }
+        }
+        "pl" => {
+            if i == 1 && v == 0 {
+                One
+            } else if v == 0 && in_range(i % 10, 2, 4) && !in_range(i % 100, 12, 14) {
+                Few
+            } else if v == 0 {
+                Many
+            } else {
+                Other
+            }
+        }
+        "ro" | "mo" => {
+            if i == 1 && v == 0 {
+                One
+            } else if v != 0
+                || n == 0_f64
+                || n_integer.is_some_and(|n| n != 1 && in_range(n % 100, 1, 19))
+            {
+                Few
+            } else {
+                Other
+            }
+        }
+        "ru" | "uk" => {
+            if v != 0 {
+                Other
+            } else if i % 10 == 1 && i % 100 != 11 {
+                One
+            } else if in_range(i % 10, 2, 4) && !in_range(i % 100, 12, 14) {
+                Few
+            } else {
+                Many
+            }
+        }
+        "sl" => match (v, i % 100) {
+            (0, 1) => One,
+            (0, 2) => Two,
+            (0, 3..=4) => Few,
+            (0, _) => Other,
+            _ => Few,
+        },
+        "az" | "bg" | "el" | "eu" | "hu" | "ka" | "kk" | "ky" | "ml" | "mn" | "nb" | "ne"
+        | "nn" | "no" | "sq" | "ta" | "te" | "tr" | "uz" => {
+            if n_in(1, 1) {
+                One
+            } else {
+                Other
+            }
+        }
+        "de" | "en" | "et" | "fi" | "fy" | "gl" | "nl" | "sv" | "sw" | "ur" => {
+            if i == 1 && v == 0 {
+                One
+            } else {
+                Other
+            }
+        }
+        _ => Other,
+    }
+}
+
+/// Relative time strings for each plural category, chosen with [`plural_category`]. Categories
+/// without a string of their own use `other`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct PluralForms {
+    #[serde(default)]
+    pub zero: Option<Cow<'static, str>>,
+    #[serde(default)]
+    pub one: Option<Cow<'static, str>>,
+    #[serde(default)]
+    pub two: Option<Cow<'static, str>>,
+    #[serde(default)]
+    pub few: Option<Cow<'static, str>>,
+    #[serde(default)]
+    pub many: Option<Cow<'static, str>>,
+    pub other: Cow<'static, str>,
+}
+
+impl PluralForms {
+    pub fn get(&self, category: PluralCategory) -> &str {
+        let form = match category {
+            PluralCategory::Zero => &self.zero,
+            PluralCategory::One => &self.one,
+            PluralCategory::Two => &self.two,
+            PluralCategory::Few => &self.few,
+            PluralCategory::Many => &self.many,
+            PluralCategory::Other => return &self.other,
+        };
+        form.as_deref().unwrap_or(&self.other)
+    }
+}
@@ -0,0 +1,66 @@
+use super::Locale;
+use crate::locales::{built_in_locales, LOCALE_EN_US};
+use lazy_static::lazy_static;
+use std::{
+    collections::BTreeMap,
+    sync::{Arc, RwLock},
+};
+
+lazy_static! {
+    static ref LOCALES: RwLock<BTreeMap<String, Arc<Locale>>> = RwLock::new(
+        built_in_locales()
+            .into_iter()
+            .map(|locale| (normalize_tag(&locale.name), Arc::new(locale)))
+            .collect()
+    );
+    static ref DEFAULT_LOCALE: RwLock<Arc<Locale>> = RwLock::new(Arc::new(LOCALE_EN_US.clone()));
+}
+
+fn normalize_tag(tag: &str) -> String {
+    tag.trim().replace('_', "-").to_lowercase()
+}
+
+/// Adds a locale to the registry under its `name`, replacing any locale already registered under
+/// the same tag. Tags are matched case-insensitively and `_` is read as `-`.
+pub fn register_locale<L: Into<Arc<Locale>>>(locale: L) -> Option<Arc<Locale>> {
+    let locale = locale.into();
+    LOCALES
+        .write()
+        .unwrap()
+        .insert(normalize_tag(&locale.name), locale)
+}
+
+/// Looks up a registered locale by BCP 47 tag. When there is no exact match, subtags are dropped
+/// from the end until one is found, so `en-GB-oxendict` falls back to `en-gb` and then `en`.
+pub fn find_locale(tag: &str) -> Option<Arc<Locale>> {
+    let locales = LOCALES.read().unwrap();
+    let mut tag = normalize_tag(tag);
+    loop {
+        if let Some(locale) = locales.get(&tag) {
+            return Some(Arc::clone(locale));
+        }
+        match tag.rfind('-') {
+            Some(index) => tag.truncate(index),
+            None => return None,
+        }
+    }
+}
+
+pub(super) fn get_locale(tag: &str) -> Option<Arc<Locale>> {
+    LOCALES.read().unwrap().get(&normalize_tag(tag)).cloned()
+}
+
+/// The tags of all registered locales, in lowercase.
+pub fn locale_names() -> Vec<String> {
+    LOCALES.read().unwrap().keys().cloned().collect()
+}
+
+/// The locale that new moments are created with. Moments share it rather than copying it.
+pub fn default_locale() -> Arc<Locale> {
+    Arc::clone(&DEFAULT_LOCALE.read().unwrap())
+}
+
+/// Replaces the locale that new moments are created with.
+pub fn set_default_locale<L: Into<Arc<Locale>>>(locale: L) {
+    *DEFAULT_LOCALE.write().unwrap() = locale.into();
+}
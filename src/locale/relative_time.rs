@@ -0,0 +1,157 @@
+use super::Locale;
+use crate::{MomentDuration, UnitOfTime};
+use lazy_static::lazy_static;
+use std::sync::RwLock;
+
+lazy_static! {
+    static ref RELATIVE_TIME_CONFIG: RwLock<RelativeTimeConfig> =
+        RwLock::new(RelativeTimeConfig::default());
+}
+
+pub type RelativeTimeRoundingFn = fn(f64) -> f64;
+
+/// The limits used to choose between relative time strings, like Moment.js's
+/// `relativeTimeThreshold`. Each value is compared against the rounded duration in that unit:
+/// seconds up to `ss` use `s` ("a few seconds"), seconds below `s` use `ss`, minutes below `m`
+/// use `mm`, and so on. Weeks are skipped unless `w` is set.
+#[allow(non_snake_case)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RelativeTimeThresholds {
+    pub ss: f64,
+    pub s: f64,
+    pub m: f64,
+    pub h: f64,
+    pub d: f64,
+    pub w: Option<f64>,
+    pub M: f64,
+}
+
+impl Default for RelativeTimeThresholds {
+    fn default() -> RelativeTimeThresholds {
+        RelativeTimeThresholds {
+            ss: 44_f64,
+            s: 45_f64,
+            m: 45_f64,
+            h: 22_f64,
+            d: 26_f64,
+            w: None,
+            M: 11_f64,
+        }
+    }
+}
+
+/// Thresholds and the rounding function used to humanize durations, like Moment.js's
+/// `relativeTimeRounding`. Rounding defaults to rounding to the nearest whole number.
+#[derive(Debug, Clone, Copy)]
+pub struct RelativeTimeConfig {
+    pub thresholds: RelativeTimeThresholds,
+    pub rounding: RelativeTimeRoundingFn,
+}
+
+impl Default for RelativeTimeConfig {
+    fn default() -> RelativeTimeConfig {
+        RelativeTimeConfig {
+            thresholds: RelativeTimeThresholds::default(),
+            rounding: f64::round,
+        }
+    }
+}
+
+/// The config used when a humanizing call is not given one.
+pub fn relative_time_config() -> RelativeTimeConfig {
+    *RELATIVE_TIME_CONFIG.read().unwrap()
+}
+
+/// Replaces the config used when a humanizing call is not given one.
+pub fn set_relative_time_config(config: RelativeTimeConfig) {
+    *RELATIVE_TIME_CONFIG.write().unwrap() = config;
+}
+
+// Like Moment.js, the singular keys always get the number 1, even when rounding leaves a
+// fraction such as 0.8 minutes.
+fn relative_time_key(
+    duration: &MomentDuration,
+    config: &RelativeTimeConfig,
+) -> (&'static str, f64) {
+    let in_unit = |unit: UnitOfTime| (config.rounding)(duration.as_unit(unit).abs());
+    let thresholds = &config.thresholds;
+    let seconds = in_unit(UnitOfTime::Second);
+    let minutes = in_unit(UnitOfTime::Minute);
+    let hours = in_unit(UnitOfTime::Hour);
+    let days = in_unit(UnitOfTime::Day);
+    let weeks = in_unit(UnitOfTime::Week);
+    let months = in_unit(UnitOfTime::Month);
+    let years = in_unit(UnitOfTime::Year);
+
+    if seconds <= thresholds.ss {
+        return ("s", seconds);
+    } else if seconds < thresholds.s {
+        return ("ss", seconds);
+    } else if minutes <= 1_f64 {
+        return ("m", 1_f64);
+    } else if minutes < thresholds.m {
+        return ("mm", minutes);
+    } else if hours <= 1_f64 {
+        return ("h", 1_f64);
+    } else if hours < thresholds.h {
+        return ("hh", hours);
+    } else if days <= 1_f64 {
+        return ("d", 1_f64);
+    } else if days < thresholds.d {
+        return ("dd", days);
+    }
+
+    if let Some(week_threshold) = thresholds.w {
+        if weeks <= 1_f64 {
+            return ("w", 1_f64);
+        } else if weeks < week_threshold {
+            return ("ww", weeks);
+        }
+    }
+
+    if months <= 1_f64 {
+        ("M", 1_f64)
+    } else if months < thresholds.M {
+        ("MM", months)
+    } else if years <= 1_f64 {
+        ("y", 1_f64)
+    } else {
+        ("yy", years)
+    }
+}
+
+impl Locale {
+    /// Describes `duration` with the locale's relative time strings, like Moment.js's
+    /// `duration.humanize()`. Unless `without_suffix` is set, positive durations are wrapped in
+    /// the `future` string and negative ones in the `past` string. Without a `config`, the global
+    /// one from [`relative_time_config`] is used.
+    pub fn humanize(
+        &self,
+        duration: &MomentDuration,
+        without_suffix: bool,
+        config: Option<&RelativeTimeConfig>,
+    ) -> String {
+        let (key, number) = match config {
+            Some(config) => relative_time_key(duration, config),
+            None => relative_time_key(duration, &relative_time_config()),
+        };
+        let number = if number == 0_f64 { 1_f64 } else { number };
+        let is_future = duration.as_unit(UnitOfTime::Millisecond) > 0_f64;
+        let output = self
+            .relative_time
+            .get_by_key(key)
+            .map(|format| format.format(&self.name, number, without_suffix, key, is_future))
+            .unwrap_or_default();
+
+        if without_suffix {
+            return output;
+        }
+
+        let suffix = if is_future {
+            &self.relative_time.future
+        } else {
+            &self.relative_time.past
+        };
+        suffix.replacen("%s", &output, 1)
+    }
+}
@@ -1,38 +1,124 @@
-#[derive(Debug, Clone)]
+use super::{plural_category, PluralForms};
+use serde::Deserialize;
+use std::{borrow::Cow, fmt};
+
+/// A function that writes a relative time like a Moment.js locale's `relativeTime` function:
+/// it is given the number, whether the string is used without the `future` or `past` suffix,
+/// the key, such as `mm`, and whether the time is in the future.
+pub type RelativeTimeFn = fn(f64, bool, &str, bool) -> String;
+
+/// One relative time string of a [`RelativeTime`].
+#[derive(Clone, Deserialize)]
+#[serde(untagged)]
+pub enum RelativeTimeFormat {
+    /// A string in which `%d` is replaced with the number.
+    Template(Cow<'static, str>),
+    /// Templates chosen by the CLDR plural category of the number in the locale's language.
+    Plural(PluralForms),
+    /// A function for strings that templates cannot express, such as those that change with the
+    /// suffix. It cannot be read from locale files.
+    #[serde(skip)]
+    Function(RelativeTimeFn),
+}
+
+impl RelativeTimeFormat {
+    /// Writes the string for `number`, using the plural rules of `language` for plural forms.
+    pub fn format(
+        &self,
+        language: &str,
+        number: f64,
+        without_suffix: bool,
+        key: &str,
+        is_future: bool,
+    ) -> String {
+        match self {
+            RelativeTimeFormat::Template(template) => {
+                template.replacen("%d", &number.to_string(), 1)
+            }
+            RelativeTimeFormat::Plural(forms) => forms
+                .get(plural_category(language, number))
+                .replacen("%d", &number.to_string(), 1),
+            RelativeTimeFormat::Function(function) => {
+                function(number, without_suffix, key, is_future)
+            }
+        }
+    }
+}
+
+impl fmt::Debug for RelativeTimeFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RelativeTimeFormat::Template(template) => write!(f, "Template({:?})", template),
+            RelativeTimeFormat::Plural(forms) => write!(f, "Plural({:?})", forms),
+            RelativeTimeFormat::Function(_) => write!(f, "Function"),
+        }
+    }
+}
+
+impl From<&'static str> for RelativeTimeFormat {
+    fn from(template: &'static str) -> RelativeTimeFormat {
+        RelativeTimeFormat::Template(Cow::Borrowed(template))
+    }
+}
+
+impl From<String> for RelativeTimeFormat {
+    fn from(template: String) -> RelativeTimeFormat {
+        RelativeTimeFormat::Template(Cow::Owned(template))
+    }
+}
+
+impl From<PluralForms> for RelativeTimeFormat {
+    fn from(forms: PluralForms) -> RelativeTimeFormat {
+        RelativeTimeFormat::Plural(forms)
+    }
+}
+
+impl From<RelativeTimeFn> for RelativeTimeFormat {
+    fn from(function: RelativeTimeFn) -> RelativeTimeFormat {
+        RelativeTimeFormat::Function(function)
+    }
+}
+
+/// The strings used to humanize durations. `future` and `past` wrap the others, with `%s`
+/// replaced by the duration.
+#[allow(non_snake_case)]
+#[derive(Debug, Clone, Deserialize)]
 pub struct RelativeTime {
-    pub future: &'static str,
-    pub past: &'static str,
-    pub s: &'static str,
-    pub ss: &'static str,
-    pub m: &'static str,
-    pub mm: &'static str,
-    pub h: &'static str,
-    pub hh: &'static str,
-    pub d: &'static str,
-    pub dd: &'static str,
-    pub M: &'static str,
-    pub MM: &'static str,
-    pub y: &'static str,
-    pub yy: &'static str,
+    pub future: Cow<'static, str>,
+    pub past: Cow<'static, str>,
+    pub s: RelativeTimeFormat,
+    pub ss: RelativeTimeFormat,
+    pub m: RelativeTimeFormat,
+    pub mm: RelativeTimeFormat,
+    pub h: RelativeTimeFormat,
+    pub hh: RelativeTimeFormat,
+    pub d: RelativeTimeFormat,
+    pub dd: RelativeTimeFormat,
+    pub w: RelativeTimeFormat,
+    pub ww: RelativeTimeFormat,
+    pub M: RelativeTimeFormat,
+    pub MM: RelativeTimeFormat,
+    pub y: RelativeTimeFormat,
+    pub yy: RelativeTimeFormat,
 }
 
 impl RelativeTime {
-    pub fn get_by_key(&self, key: &str) -> Option<&'static str> {
+    pub fn get_by_key(&self, key: &str) -> Option<&RelativeTimeFormat> {
         match key {
-            "future" => Some(self.future),
-            "past" => Some(self.past),
-            "s" => Some(self.s),
-            "ss" => Some(self.ss),
-            "m" => Some(self.m),
-            "mm" => Some(self.mm),
-            "h" => Some(self.h),
-            "hh" => Some(self.hh),
-            "d" => Some(self.d),
-            "dd" => Some(self.dd),
-            "M" => Some(self.M),
-            "MM" => Some(self.MM),
-            "y" => Some(self.y),
-            "yy" => Some(self.yy),
+            "s" => Some(&self.s),
+            "ss" => Some(&self.ss),
+            "m" => Some(&self.m),
+            "mm" => Some(&self.mm),
+            "h" => Some(&self.h),
+            "hh" => Some(&self.hh),
+            "d" => Some(&self.d),
+            "dd" => Some(&self.dd),
+            "w" => Some(&self.w),
+            "ww" => Some(&self.ww),
+            "M" => Some(&self.M),
+            "MM" => Some(&self.MM),
+            "y" => Some(&self.y),
+            "yy" => Some(&self.yy),
             _ => None,
         }
     }
@@ -0,0 +1,45 @@
+use crate::tz::metazone;
+use serde::Deserialize;
+use std::{borrow::Cow, collections::BTreeMap};
+
+/// The long names of a zone or metazone in standard and daylight saving time.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct TimeZoneName {
+    #[serde(default)]
+    pub standard: Option<Cow<'static, str>>,
+    #[serde(default)]
+    pub daylight: Option<Cow<'static, str>>,
+}
+
+/// Long names of time zones, such as "Eastern Standard Time", used by the `zz` formatting token.
+/// Names are keyed by IANA zone, such as `Europe/London`, or by CLDR metazone, such as
+/// `America_Eastern`, and a zone's own names take precedence over its metazone's. Zones without
+/// a long name are formatted with their abbreviation.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(from = "BTreeMap<String, TimeZoneName>")]
+pub struct TimeZoneNames(pub Vec<(Cow<'static, str>, TimeZoneName)>);
+
+impl TimeZoneNames {
+    pub fn get(&self, zone: &str, is_dst: bool) -> Option<&str> {
+        let name = |key: &str| {
+            let (_, name) = self.0.iter().find(|(name_key, _)| *name_key == key)?;
+            if is_dst {
+                name.daylight.as_deref()
+            } else {
+                name.standard.as_deref()
+            }
+        };
+        name(zone).or_else(|| metazone(zone).and_then(name))
+    }
+}
+
+impl From<BTreeMap<String, TimeZoneName>> for TimeZoneNames {
+    fn from(names: BTreeMap<String, TimeZoneName>) -> TimeZoneNames {
+        TimeZoneNames(
+            names
+                .into_iter()
+                .map(|(key, name)| (Cow::Owned(key), name))
+                .collect(),
+        )
+    }
+}
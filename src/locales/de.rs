@@ -0,0 +1,190 @@
+// Generated by tools/generate_locales.js from Moment.js's `de` locale.
+use crate::locale::*;
+use lazy_static::lazy_static;
+use regex::Regex;
+
+lazy_static! {
+    pub static ref LOCALE_DE: Locale = Locale {
+        name: "de".into(),
+        invalid_date: "Invalid date".into(),
+        months: MonthStrings(
+            "Januar".into(),
+            "Februar".into(),
+            "März".into(),
+            "April".into(),
+            "Mai".into(),
+            "Juni".into(),
+            "Juli".into(),
+            "August".into(),
+            "September".into(),
+            "Oktober".into(),
+            "November".into(),
+            "Dezember".into(),
+        ),
+        months_short: MonthStrings(
+            "Jan.".into(),
+            "Feb.".into(),
+            "März".into(),
+            "Apr.".into(),
+            "Mai".into(),
+            "Juni".into(),
+            "Juli".into(),
+            "Aug.".into(),
+            "Sep.".into(),
+            "Okt.".into(),
+            "Nov.".into(),
+            "Dez.".into(),
+        ),
+        weekdays: WeekDayStrings(
+            "Sonntag".into(),
+            "Montag".into(),
+            "Dienstag".into(),
+            "Mittwoch".into(),
+            "Donnerstag".into(),
+            "Freitag".into(),
+            "Samstag".into(),
+        ),
+        weekdays_short: WeekDayStrings(
+            "So.".into(),
+            "Mo.".into(),
+            "Di.".into(),
+            "Mi.".into(),
+            "Do.".into(),
+            "Fr.".into(),
+            "Sa.".into(),
+        ),
+        weekdays_min: WeekDayStrings(
+            "So".into(),
+            "Mo".into(),
+            "Di".into(),
+            "Mi".into(),
+            "Do".into(),
+            "Fr".into(),
+            "Sa".into(),
+        ),
+        long_date_format: LongDateFormat {
+            LT: "HH:mm".into(),
+            LTS: "HH:mm:ss".into(),
+            L: "DD.MM.YYYY".into(),
+            LL: "D. MMMM YYYY".into(),
+            LLL: "D. MMMM YYYY HH:mm".into(),
+            LLLL: "dddd, D. MMMM YYYY HH:mm".into(),
+        },
+        calendar: Calendar {
+            same_day: "[heute um] LT [Uhr]".into(),
+            next_day: "[morgen um] LT [Uhr]".into(),
+            next_week: "dddd [um] LT [Uhr]".into(),
+            last_day: "[gestern um] LT [Uhr]".into(),
+            last_week: "[letzten] dddd [um] LT [Uhr]".into(),
+            same_else: "L".into(),
+        },
+        relative_time: RelativeTime {
+            future: "in %s".into(),
+            past: "vor %s".into(),
+            s: "ein paar Sekunden".into(),
+            ss: "%d Sekunden".into(),
+            m: RelativeTimeFormat::Function(relative_time_m),
+            mm: "%d Minuten".into(),
+            h: RelativeTimeFormat::Function(relative_time_h),
+            hh: "%d Stunden".into(),
+            d: RelativeTimeFormat::Function(relative_time_d),
+            dd: RelativeTimeFormat::Function(relative_time_dd),
+            w: RelativeTimeFormat::Function(relative_time_w),
+            ww: "%d Wochen".into(),
+            M: RelativeTimeFormat::Function(relative_time_M),
+            MM: RelativeTimeFormat::Function(relative_time_MM),
+            y: RelativeTimeFormat::Function(relative_time_y),
+            yy: RelativeTimeFormat::Function(relative_time_yy),
+        },
+        time_zone_names: TimeZoneNames::default(),
+        day_of_month_ordinal_parse: Regex::new(r"\d{1,2}\.").unwrap(),
+        ordinal: Ordinal {
+            rules: vec![],
+            default: "%d.".into(),
+        },
+        week: Week { dow: 1, doy: 4 },
+        meridiem: Meridiem {
+            rules: vec![MeridiemRule {
+                before: 1200,
+                text: "AM".into(),
+            }],
+            default: "PM".into(),
+        },
+        meridiem_parse: Regex::new(r"(?i)[ap]\.?m?\.?").unwrap(),
+        pm_parse: Regex::new(r"(?i)^p").unwrap(),
+    };
+}
+
+fn relative_time_m(number: f64, without_suffix: bool, _: &str, is_future: bool) -> String {
+    let template = match (without_suffix, is_future) {
+        (true, _) => "eine Minute",
+        (false, _) => "einer Minute",
+    };
+    template.replacen("%d", &number.to_string(), 1)
+}
+
+fn relative_time_h(number: f64, without_suffix: bool, _: &str, is_future: bool) -> String {
+    let template = match (without_suffix, is_future) {
+        (true, _) => "eine Stunde",
+        (false, _) => "einer Stunde",
+    };
+    template.replacen("%d", &number.to_string(), 1)
+}
+
+fn relative_time_d(number: f64, without_suffix: bool, _: &str, is_future: bool) -> String {
+    let template = match (without_suffix, is_future) {
+        (true, _) => "ein Tag",
+        (false, _) => "einem Tag",
+    };
+    template.replacen("%d", &number.to_string(), 1)
+}
+
+fn relative_time_dd(number: f64, without_suffix: bool, _: &str, is_future: bool) -> String {
+    let template = match (without_suffix, is_future) {
+        (true, _) => "%d Tage",
+        (false, _) => "%d Tagen",
+    };
+    template.replacen("%d", &number.to_string(), 1)
+}
+
+fn relative_time_w(number: f64, without_suffix: bool, _: &str, is_future: bool) -> String {
+    let template = match (without_suffix, is_future) {
+        (true, _) => "eine Woche",
+        (false, _) => "einer Woche",
+    };
+    template.replacen("%d", &number.to_string(), 1)
+}
+
+#[allow(non_snake_case)]
+fn relative_time_M(number: f64, without_suffix: bool, _: &str, is_future: bool) -> String {
+    let template = match (without_suffix, is_future) {
+        (true, _) => "ein Monat",
+        (false, _) => "einem Monat",
+    };
+    template.replacen("%d", &number.to_string(), 1)
+}
+
+#[allow(non_snake_case)]
+fn relative_time_MM(number: f64, without_suffix: bool, _: &str, is_future: bool) -> String {
+    let template = match (without_suffix, is_future) {
+        (true, _) => "%d Monate",
+        (false, _) => "%d Monaten",
+    };
+    template.replacen("%d", &number.to_string(), 1)
+}
+
+fn relative_time_y(number: f64, without_suffix: bool, _: &str, is_future: bool) -> String {
+    let template = match (without_suffix, is_future) {
+        (true, _) => "ein Jahr",
+        (false, _) => "einem Jahr",
+    };
+    template.replacen("%d", &number.to_string(), 1)
+}
+
+fn relative_time_yy(number: f64, without_suffix: bool, _: &str, is_future: bool) -> String {
+    let template = match (without_suffix, is_future) {
+        (true, _) => "%d Jahre",
+        (false, _) => "%d Jahren",
+    };
+    template.replacen("%d", &number.to_string(), 1)
+}
@@ -0,0 +1,137 @@
+// Generated by tools/generate_locales.js from Moment.js's `en-ca` locale.
+use crate::locale::*;
+use lazy_static::lazy_static;
+use regex::Regex;
+
+lazy_static! {
+    pub static ref LOCALE_EN_CA: Locale = Locale {
+        name: "en-ca".into(),
+        invalid_date: "Invalid date".into(),
+        months: MonthStrings(
+            "January".into(),
+            "February".into(),
+            "March".into(),
+            "April".into(),
+            "May".into(),
+            "June".into(),
+            "July".into(),
+            "August".into(),
+            "September".into(),
+            "October".into(),
+            "November".into(),
+            "December".into(),
+        ),
+        months_short: MonthStrings(
+            "Jan".into(),
+            "Feb".into(),
+            "Mar".into(),
+            "Apr".into(),
+            "May".into(),
+            "Jun".into(),
+            "Jul".into(),
+            "Aug".into(),
+            "Sep".into(),
+            "Oct".into(),
+            "Nov".into(),
+            "Dec".into(),
+        ),
+        weekdays: WeekDayStrings(
+            "Sunday".into(),
+            "Monday".into(),
+            "Tuesday".into(),
+            "Wednesday".into(),
+            "Thursday".into(),
+            "Friday".into(),
+            "Saturday".into(),
+        ),
+        weekdays_short: WeekDayStrings(
+            "Sun".into(),
+            "Mon".into(),
+            "Tue".into(),
+            "Wed".into(),
+            "Thu".into(),
+            "Fri".into(),
+            "Sat".into(),
+        ),
+        weekdays_min: WeekDayStrings(
+            "Su".into(),
+            "Mo".into(),
+            "Tu".into(),
+            "We".into(),
+            "Th".into(),
+            "Fr".into(),
+            "Sa".into(),
+        ),
+        long_date_format: LongDateFormat {
+            LT: "h:mm A".into(),
+            LTS: "h:mm:ss A".into(),
+            L: "YYYY-MM-DD".into(),
+            LL: "MMMM D, YYYY".into(),
+            LLL: "MMMM D, YYYY h:mm A".into(),
+            LLLL: "dddd, MMMM D, YYYY h:mm A".into(),
+        },
+        calendar: Calendar {
+            same_day: "[Today at] LT".into(),
+            next_day: "[Tomorrow at] LT".into(),
+            next_week: "dddd [at] LT".into(),
+            last_day: "[Yesterday at] LT".into(),
+            last_week: "[Last] dddd [at] LT".into(),
+            same_else: "L".into(),
+        },
+        relative_time: RelativeTime {
+            future: "in %s".into(),
+            past: "%s ago".into(),
+            s: "a few seconds".into(),
+            ss: "%d seconds".into(),
+            m: "a minute".into(),
+            mm: "%d minutes".into(),
+            h: "an hour".into(),
+            hh: "%d hours".into(),
+            d: "a day".into(),
+            dd: "%d days".into(),
+            w: "a week".into(),
+            ww: "%d weeks".into(),
+            M: "a month".into(),
+            MM: "%d months".into(),
+            y: "a year".into(),
+            yy: "%d years".into(),
+        },
+        time_zone_names: TimeZoneNames::default(),
+        day_of_month_ordinal_parse: Regex::new(r"\d{1,2}(st|nd|rd|th)").unwrap(),
+        ordinal: Ordinal {
+            rules: vec![
+                OrdinalRule {
+                    modulo: Some(100),
+                    values: vec![11, 12, 13],
+                    format: "%dth".into(),
+                },
+                OrdinalRule {
+                    modulo: Some(10),
+                    values: vec![1],
+                    format: "%dst".into(),
+                },
+                OrdinalRule {
+                    modulo: Some(10),
+                    values: vec![2],
+                    format: "%dnd".into(),
+                },
+                OrdinalRule {
+                    modulo: Some(10),
+                    values: vec![3],
+                    format: "%drd".into(),
+                },
+            ],
+            default: "%dth".into(),
+        },
+        week: Week { dow: 0, doy: 6 },
+        meridiem: Meridiem {
+            rules: vec![MeridiemRule {
+                before: 1200,
+                text: "AM".into(),
+            }],
+            default: "PM".into(),
+        },
+        meridiem_parse: Regex::new(r"(?i)[ap]\.?m?\.?").unwrap(),
+        pm_parse: Regex::new(r"(?i)^p").unwrap(),
+    };
+}
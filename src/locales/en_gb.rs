@@ -4,90 +4,197 @@ use regex::Regex;
 
 lazy_static! {
     pub static ref LOCALE_EN_GB: Locale = Locale {
-        invalid_date: "Invalid Date",
+        name: "en-gb".into(),
+        invalid_date: "Invalid Date".into(),
         months: MonthStrings(
-            "January",
-            "February",
-            "March",
-            "April",
-            "May",
-            "June",
-            "July",
-            "August",
-            "September",
-            "October",
-            "November",
-            "December"
+            "January".into(),
+            "February".into(),
+            "March".into(),
+            "April".into(),
+            "May".into(),
+            "June".into(),
+            "July".into(),
+            "August".into(),
+            "September".into(),
+            "October".into(),
+            "November".into(),
+            "December".into()
         ),
         months_short: MonthStrings(
-            "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec"
+            "Jan".into(),
+            "Feb".into(),
+            "Mar".into(),
+            "Apr".into(),
+            "May".into(),
+            "Jun".into(),
+            "Jul".into(),
+            "Aug".into(),
+            "Sep".into(),
+            "Oct".into(),
+            "Nov".into(),
+            "Dec".into()
         ),
         weekdays: WeekDayStrings(
-            "Sunday",
-            "Monday",
-            "Tuesday",
-            "Wednesday",
-            "Thursday",
-            "Friday",
-            "Saturday"
+            "Sunday".into(),
+            "Monday".into(),
+            "Tuesday".into(),
+            "Wednesday".into(),
+            "Thursday".into(),
+            "Friday".into(),
+            "Saturday".into()
+        ),
+        weekdays_short: WeekDayStrings(
+            "Sun".into(),
+            "Mon".into(),
+            "Tue".into(),
+            "Wed".into(),
+            "Thu".into(),
+            "Fri".into(),
+            "Sat".into()
+        ),
+        weekdays_min: WeekDayStrings(
+            "Su".into(),
+            "Mo".into(),
+            "Tu".into(),
+            "We".into(),
+            "Th".into(),
+            "Fr".into(),
+            "Sa".into()
         ),
-        weekdays_short: WeekDayStrings("Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"),
-        weekdays_min: WeekDayStrings("Su", "Mo", "Tu", "We", "Th", "Fr", "Sa"),
         long_date_format: LongDateFormat {
-            LT: "HH:mm",
-            LTS: "HH:mm:ss",
-            L: "DD/MM/YYYY",
-            LL: "D MMMM YYYY",
-            LLL: "D MMMM YYYY HH:mm",
-            LLLL: "dddd, D MMMM YYYY HH:mm"
+            LT: "HH:mm".into(),
+            LTS: "HH:mm:ss".into(),
+            L: "DD/MM/YYYY".into(),
+            LL: "D MMMM YYYY".into(),
+            LLL: "D MMMM YYYY HH:mm".into(),
+            LLLL: "dddd, D MMMM YYYY HH:mm".into()
         },
         calendar: Calendar {
-            same_day: "[Today at] LT",
-            next_day: "[Tomorrow at] LT",
-            next_week: "dddd [at] LT",
-            last_day: "[Yesterday at] LT",
-            last_week: "[Last] dddd [at] LT",
-            same_else: "L",
+            same_day: "[Today at] LT".into(),
+            next_day: "[Tomorrow at] LT".into(),
+            next_week: "dddd [at] LT".into(),
+            last_day: "[Yesterday at] LT".into(),
+            last_week: "[Last] dddd [at] LT".into(),
+            same_else: "L".into(),
         },
         relative_time: RelativeTime {
-            future: "in %s",
-            past: "%s ago",
-            s: "a few seconds",
-            ss: "%d seconds",
-            m: "a minute",
-            mm: "%d minutes",
-            h: "an hour",
-            hh: "%d hours",
-            d: "a day",
-            dd: "%d days",
-            M: "a month",
-            MM: "%d months",
-            y: "a year",
-            yy: "%d years",
+            future: "in %s".into(),
+            past: "%s ago".into(),
+            s: "a few seconds".into(),
+            ss: "%d seconds".into(),
+            m: "a minute".into(),
+            mm: "%d minutes".into(),
+            h: "an hour".into(),
+            hh: "%d hours".into(),
+            d: "a day".into(),
+            dd: "%d days".into(),
+            w: "a week".into(),
+            ww: "%d weeks".into(),
+            M: "a month".into(),
+            MM: "%d months".into(),
+            y: "a year".into(),
+            yy: "%d years".into(),
         },
+        time_zone_names: TimeZoneNames(vec![
+            (
+                "UTC".into(),
+                TimeZoneName {
+                    standard: Some("Coordinated Universal Time".into()),
+                    daylight: None,
+                },
+            ),
+            (
+                "GMT".into(),
+                TimeZoneName {
+                    standard: Some("Greenwich Mean Time".into()),
+                    daylight: None,
+                },
+            ),
+            (
+                "Europe/London".into(),
+                TimeZoneName {
+                    standard: None,
+                    daylight: Some("British Summer Time".into()),
+                },
+            ),
+            (
+                "Europe/Dublin".into(),
+                TimeZoneName {
+                    standard: None,
+                    daylight: Some("Irish Standard Time".into()),
+                },
+            ),
+            (
+                "Europe_Western".into(),
+                TimeZoneName {
+                    standard: Some("Western European Standard Time".into()),
+                    daylight: Some("Western European Summer Time".into()),
+                },
+            ),
+            (
+                "Europe_Central".into(),
+                TimeZoneName {
+                    standard: Some("Central European Standard Time".into()),
+                    daylight: Some("Central European Summer Time".into()),
+                },
+            ),
+            (
+                "Europe_Eastern".into(),
+                TimeZoneName {
+                    standard: Some("Eastern European Standard Time".into()),
+                    daylight: Some("Eastern European Summer Time".into()),
+                },
+            ),
+            (
+                "America_Eastern".into(),
+                TimeZoneName {
+                    standard: Some("Eastern Standard Time".into()),
+                    daylight: Some("Eastern Daylight Time".into()),
+                },
+            ),
+            (
+                "America_Pacific".into(),
+                TimeZoneName {
+                    standard: Some("Pacific Standard Time".into()),
+                    daylight: Some("Pacific Daylight Time".into()),
+                },
+            ),
+        ]),
         day_of_month_ordinal_parse: Regex::new(r"\d{1,2}(st|nd|rd|th)").unwrap(),
-        ordinal: |number| {
-            let b = number % 10;
-            let output = if !!(number % 100 / 10) == 1 {
-                "th"
-            } else {
-                match b {
-                    1 => "st",
-                    2 => "nd",
-                    3 => "rd",
-                    _ => "th",
-                }
-            };
-
-            format!("{}{}", number, output)
+        ordinal: Ordinal {
+            rules: vec![
+                OrdinalRule {
+                    modulo: Some(100),
+                    values: vec![11, 12, 13],
+                    format: "%dth".into(),
+                },
+                OrdinalRule {
+                    modulo: Some(10),
+                    values: vec![1],
+                    format: "%dst".into(),
+                },
+                OrdinalRule {
+                    modulo: Some(10),
+                    values: vec![2],
+                    format: "%dnd".into(),
+                },
+                OrdinalRule {
+                    modulo: Some(10),
+                    values: vec![3],
+                    format: "%drd".into(),
+                },
+            ],
+            default: "%dth".into(),
         },
         week: Week { dow: 1, doy: 4 },
-        meridiem: |hour, _| {
-            if hour < 12 {
-                String::from("AM")
-            } else {
-                String::from("PM")
-            }
+        meridiem: Meridiem {
+            rules: vec![MeridiemRule {
+                before: 1200,
+                text: "AM".into(),
+            }],
+            default: "PM".into(),
         },
+        meridiem_parse: Regex::new(r"[ap]\.?m?\.?").unwrap(),
+        pm_parse: Regex::new(r"(?i)^p").unwrap(),
     };
 }
@@ -0,0 +1,120 @@
+// Generated by tools/generate_locales.js from Moment.js's `fr` locale.
+use crate::locale::*;
+use lazy_static::lazy_static;
+use regex::Regex;
+
+lazy_static! {
+    pub static ref LOCALE_FR: Locale = Locale {
+        name: "fr".into(),
+        invalid_date: "Invalid date".into(),
+        months: MonthStrings(
+            "janvier".into(),
+            "février".into(),
+            "mars".into(),
+            "avril".into(),
+            "mai".into(),
+            "juin".into(),
+            "juillet".into(),
+            "août".into(),
+            "septembre".into(),
+            "octobre".into(),
+            "novembre".into(),
+            "décembre".into(),
+        ),
+        months_short: MonthStrings(
+            "janv.".into(),
+            "févr.".into(),
+            "mars".into(),
+            "avr.".into(),
+            "mai".into(),
+            "juin".into(),
+            "juil.".into(),
+            "août".into(),
+            "sept.".into(),
+            "oct.".into(),
+            "nov.".into(),
+            "déc.".into(),
+        ),
+        weekdays: WeekDayStrings(
+            "dimanche".into(),
+            "lundi".into(),
+            "mardi".into(),
+            "mercredi".into(),
+            "jeudi".into(),
+            "vendredi".into(),
+            "samedi".into(),
+        ),
+        weekdays_short: WeekDayStrings(
+            "dim.".into(),
+            "lun.".into(),
+            "mar.".into(),
+            "mer.".into(),
+            "jeu.".into(),
+            "ven.".into(),
+            "sam.".into(),
+        ),
+        weekdays_min: WeekDayStrings(
+            "di".into(),
+            "lu".into(),
+            "ma".into(),
+            "me".into(),
+            "je".into(),
+            "ve".into(),
+            "sa".into(),
+        ),
+        long_date_format: LongDateFormat {
+            LT: "HH:mm".into(),
+            LTS: "HH:mm:ss".into(),
+            L: "DD/MM/YYYY".into(),
+            LL: "D MMMM YYYY".into(),
+            LLL: "D MMMM YYYY HH:mm".into(),
+            LLLL: "dddd D MMMM YYYY HH:mm".into(),
+        },
+        calendar: Calendar {
+            same_day: "[Aujourd’hui à] LT".into(),
+            next_day: "[Demain à] LT".into(),
+            next_week: "dddd [à] LT".into(),
+            last_day: "[Hier à] LT".into(),
+            last_week: "dddd [dernier à] LT".into(),
+            same_else: "L".into(),
+        },
+        relative_time: RelativeTime {
+            future: "dans %s".into(),
+            past: "il y a %s".into(),
+            s: "quelques secondes".into(),
+            ss: "%d secondes".into(),
+            m: "une minute".into(),
+            mm: "%d minutes".into(),
+            h: "une heure".into(),
+            hh: "%d heures".into(),
+            d: "un jour".into(),
+            dd: "%d jours".into(),
+            w: "une semaine".into(),
+            ww: "%d semaines".into(),
+            M: "un mois".into(),
+            MM: "%d mois".into(),
+            y: "un an".into(),
+            yy: "%d ans".into(),
+        },
+        time_zone_names: TimeZoneNames::default(),
+        day_of_month_ordinal_parse: Regex::new(r"\d{1,2}(er|)").unwrap(),
+        ordinal: Ordinal {
+            rules: vec![OrdinalRule {
+                modulo: None,
+                values: vec![1],
+                format: "%der".into(),
+            }],
+            default: "%d".into(),
+        },
+        week: Week { dow: 1, doy: 4 },
+        meridiem: Meridiem {
+            rules: vec![MeridiemRule {
+                before: 1200,
+                text: "AM".into(),
+            }],
+            default: "PM".into(),
+        },
+        meridiem_parse: Regex::new(r"(?i)[ap]\.?m?\.?").unwrap(),
+        pm_parse: Regex::new(r"(?i)^p").unwrap(),
+    };
+}
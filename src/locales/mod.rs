@@ -1,5 +1,70 @@
+// Generated by tools/generate_locales.js. Each Moment.js locale is behind a `locale-*` feature.
+use crate::locale::Locale;
+
 mod en_gb;
 pub use en_gb::LOCALE_EN_GB;
 
 mod en_us;
 pub use en_us::LOCALE_EN_US;
+
+#[cfg(feature = "locale-de")]
+mod de;
+#[cfg(feature = "locale-de")]
+pub use de::LOCALE_DE;
+#[cfg(feature = "locale-en-au")]
+mod en_au;
+#[cfg(feature = "locale-en-au")]
+pub use en_au::LOCALE_EN_AU;
+#[cfg(feature = "locale-en-ca")]
+mod en_ca;
+#[cfg(feature = "locale-en-ca")]
+pub use en_ca::LOCALE_EN_CA;
+#[cfg(feature = "locale-en-ie")]
+mod en_ie;
+#[cfg(feature = "locale-en-ie")]
+pub use en_ie::LOCALE_EN_IE;
+#[cfg(feature = "locale-en-il")]
+mod en_il;
+#[cfg(feature = "locale-en-il")]
+pub use en_il::LOCALE_EN_IL;
+#[cfg(feature = "locale-en-in")]
+mod en_in;
+#[cfg(feature = "locale-en-in")]
+pub use en_in::LOCALE_EN_IN;
+#[cfg(feature = "locale-en-nz")]
+mod en_nz;
+#[cfg(feature = "locale-en-nz")]
+pub use en_nz::LOCALE_EN_NZ;
+#[cfg(feature = "locale-en-sg")]
+mod en_sg;
+#[cfg(feature = "locale-en-sg")]
+pub use en_sg::LOCALE_EN_SG;
+#[cfg(feature = "locale-fr")]
+mod fr;
+#[cfg(feature = "locale-fr")]
+pub use fr::LOCALE_FR;
+
+/// The compiled-in locales, which are registered when the registry is first used.
+pub(crate) fn built_in_locales() -> Vec<Locale> {
+    #[allow(unused_mut)]
+    let mut locales = vec![LOCALE_EN_US.clone(), LOCALE_EN_GB.clone()];
+    #[cfg(feature = "locale-de")]
+    locales.push(LOCALE_DE.clone());
+    #[cfg(feature = "locale-en-au")]
+    locales.push(LOCALE_EN_AU.clone());
+    #[cfg(feature = "locale-en-ca")]
+    locales.push(LOCALE_EN_CA.clone());
+    #[cfg(feature = "locale-en-ie")]
+    locales.push(LOCALE_EN_IE.clone());
+    #[cfg(feature = "locale-en-il")]
+    locales.push(LOCALE_EN_IL.clone());
+    #[cfg(feature = "locale-en-in")]
+    locales.push(LOCALE_EN_IN.clone());
+    #[cfg(feature = "locale-en-nz")]
+    locales.push(LOCALE_EN_NZ.clone());
+    #[cfg(feature = "locale-en-sg")]
+    locales.push(LOCALE_EN_SG.clone());
+    #[cfg(feature = "locale-fr")]
+    locales.push(LOCALE_FR.clone());
+    locales
+}
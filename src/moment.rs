@@ -1,107 +1,229 @@
 use crate::{
-    duration,
-    locale::{Calendar, CalendarFormatFn, Locale},
-    locales::LOCALE_EN_US,
-    UnitOfTime,
+    locale::{
+        default_locale, find_locale, parse_utc_offset, Calendar, CalendarFormatFn, CompiledFormat,
+        Locale, ParseMode, RelativeTimeConfig,
+    },
+    tz::{from_local, Disambiguation, OffsetName, PosixTz, Transition, Tz},
+    MomentDuration, MomentError, UnitOfTime,
 };
-use chrono::{prelude::*, Duration};
-use std::{collections::HashSet, fmt::Debug, ops::Deref};
+use chrono::{prelude::*, Duration, Months};
+use std::{convert::TryFrom, fmt::Debug, ops::Deref, sync::Arc};
+
+fn days_in_month(year: i32, month: u32) -> Option<u32> {
+    let next_month = if month == 12 {
+        NaiveDate::from_ymd_opt(year.checked_add(1)?, 1, 1)
+    } else {
+        NaiveDate::from_ymd_opt(year, month + 1, 1)
+    }?;
+    next_month.pred_opt().map(|date| date.day())
+}
+
+// Splits the time between two instants into whole calendar months and the remaining time, the
+// same way Moment.js builds a duration from two moments.
+fn positive_moments_difference(
+    base: &DateTime<FixedOffset>,
+    other: &DateTime<FixedOffset>,
+) -> MomentDuration {
+    let mut months = i64::from(other.month0()) - i64::from(base.month0())
+        + (i64::from(other.year()) - i64::from(base.year())) * 12;
+    let add_months = |months: i64| {
+        u32::try_from(months)
+            .ok()
+            .and_then(|months| base.checked_add_months(Months::new(months)))
+    };
+    if add_months(months).is_none_or(|date_time| date_time > *other) {
+        months -= 1;
+    }
+    let time = add_months(months).map_or_else(Duration::zero, |date_time| {
+        other.signed_duration_since(date_time)
+    });
+    MomentDuration::from(time).months(months)
+}
+
+fn moments_difference(
+    base: &DateTime<FixedOffset>,
+    other: &DateTime<FixedOffset>,
+) -> MomentDuration {
+    let other = other.with_timezone(&base.timezone());
+    if *base < other {
+        return positive_moments_difference(base, &other);
+    }
+    let difference = positive_moments_difference(&other, base);
+    MomentDuration::from(-difference.time).months(-difference.months)
+}
+
+/// The offset given to [`Moment::utc_offset`]: either minutes east of UTC or a string containing
+/// an offset such as `+05:30`, `-0800` or `Z`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UtcOffset {
+    Minutes(i32),
+    String(String),
+}
+
+impl From<i32> for UtcOffset {
+    fn from(minutes: i32) -> UtcOffset {
+        UtcOffset::Minutes(minutes)
+    }
+}
+
+impl From<&str> for UtcOffset {
+    fn from(offset: &str) -> UtcOffset {
+        UtcOffset::String(String::from(offset))
+    }
+}
+
+impl From<String> for UtcOffset {
+    fn from(offset: String) -> UtcOffset {
+        UtcOffset::String(offset)
+    }
+}
 
 #[derive(Debug, Clone)]
 pub struct Moment<T: TimeZone + Debug> {
-    date_time: DateTime<FixedOffset>,
-    time_zone: T,
-    locale: Locale,
+    date_time: DateTime<T>,
+    locale: Arc<Locale>,
+    disambiguation: Disambiguation,
 }
 
 impl<T: TimeZone + Debug> Deref for Moment<T> {
-    type Target = DateTime<FixedOffset>;
+    type Target = DateTime<T>;
 
     fn deref(&self) -> &Self::Target {
         &self.date_time
     }
 }
 
+impl<T: TimeZone + Debug> From<DateTime<T>> for Moment<T> {
+    fn from(date_time: DateTime<T>) -> Moment<T> {
+        Moment {
+            date_time,
+            locale: default_locale(),
+            disambiguation: Disambiguation::default(),
+        }
+    }
+}
+
 impl Moment<Local> {
-    pub fn new() -> Result<Moment<Local>, String> {
-        let now = Local::now();
-        let time_zone = now.timezone();
+    pub fn new() -> Result<Moment<Local>, MomentError> {
         Ok(Moment {
-            time_zone,
-            date_time: now
-                .with_timezone(&time_zone.offset_from_utc_datetime(&Utc::now().naive_utc())),
-            locale: LOCALE_EN_US.clone(),
+            date_time: Local::now(),
+            locale: default_locale(),
+            disambiguation: Disambiguation::default(),
         })
     }
 
     pub fn utc(self) -> Moment<Utc> {
-        Moment {
-            time_zone: Utc,
-            date_time: self.date_time,
-            locale: LOCALE_EN_US.clone(),
-        }
+        self.with_time_zone(&Utc)
     }
 }
 
 impl Moment<FixedOffset> {
-    pub fn new<T: Into<String>>(date: T, format: Option<T>) -> Result<Moment<FixedOffset>, String> {
+    pub fn new<T: Into<String>>(
+        date: T,
+        format: Option<T>,
+    ) -> Result<Moment<FixedOffset>, MomentError> {
         let date_string = date.into();
 
         if format.is_none() {
             if let Ok(date_time) = DateTime::parse_from_rfc3339(&date_string) {
                 return Ok(Moment {
-                    time_zone: date_time.timezone(),
                     date_time,
-                    locale: LOCALE_EN_US.clone(),
+                    locale: default_locale(),
+                    disambiguation: Disambiguation::default(),
                 });
             }
 
             if let Ok(date_time) = DateTime::parse_from_rfc2822(&date_string) {
                 return Ok(Moment {
-                    time_zone: date_time.timezone(),
                     date_time,
-                    locale: LOCALE_EN_US.clone(),
+                    locale: default_locale(),
+                    disambiguation: Disambiguation::default(),
                 });
             }
 
-            return Err(format!(
-                "Could not parse date, \"{}\", as RFC 3339 / ISO 8601 or RFC 2822.",
-                date_string
-            ));
+            return Err(MomentError::Parse {
+                input: date_string,
+                format: None,
+                offset: 0,
+            });
         }
 
         let format_string = format.unwrap().into();
 
         if let Ok(date_time) = DateTime::parse_from_str(&date_string, &format_string) {
             return Ok(Moment {
-                time_zone: date_time.timezone(),
                 date_time,
-                locale: LOCALE_EN_US.clone(),
+                locale: default_locale(),
+                disambiguation: Disambiguation::default(),
             });
         }
 
-        Err(format!(
-            "Date, \"{}\", could not be parsed with format string \"{}\"",
-            date_string, format_string
+        Err(MomentError::Parse {
+            input: date_string,
+            format: Some(format_string),
+            offset: 0,
+        })
+    }
+
+    pub fn parse<T: Into<String>>(date: T, format: T) -> Result<Moment<FixedOffset>, MomentError> {
+        Moment::<FixedOffset>::parse_with_locale(date, format, default_locale())
+    }
+
+    pub fn parse_with_locale<T: Into<String>, L: Into<Arc<Locale>>>(
+        date: T,
+        format: T,
+        locale: L,
+    ) -> Result<Moment<FixedOffset>, MomentError> {
+        let locale = locale.into();
+        let date_time = locale.parse(&date.into(), &format.into())?;
+        Ok(Moment {
+            date_time,
+            locale,
+            disambiguation: Disambiguation::default(),
+        })
+    }
+
+    pub fn parse_formats<T: AsRef<str>>(
+        date: &str,
+        formats: &[T],
+        mode: ParseMode,
+    ) -> Result<(Moment<FixedOffset>, usize), MomentError> {
+        Moment::<FixedOffset>::parse_formats_with_locale(date, formats, mode, default_locale())
+    }
+
+    pub fn parse_formats_with_locale<T: AsRef<str>, L: Into<Arc<Locale>>>(
+        date: &str,
+        formats: &[T],
+        mode: ParseMode,
+        locale: L,
+    ) -> Result<(Moment<FixedOffset>, usize), MomentError> {
+        let locale = locale.into();
+        let (date_time, index) = locale.parse_formats(date, formats, mode)?;
+        Ok((
+            Moment {
+                date_time,
+                locale,
+                disambiguation: Disambiguation::default(),
+            },
+            index,
         ))
     }
 
     pub fn utc(self) -> Moment<Utc> {
-        Moment {
-            time_zone: Utc,
-            date_time: self.date_time,
-            locale: LOCALE_EN_US.clone(),
-        }
+        self.with_time_zone(&Utc)
     }
 }
 
 impl Moment<Utc> {
-    pub fn utc<T: Into<String>>(date: Option<T>, format: Option<T>) -> Result<Moment<Utc>, String> {
+    pub fn utc<T: Into<String>>(
+        date: Option<T>,
+        format: Option<T>,
+    ) -> Result<Moment<Utc>, MomentError> {
         if date.is_none() {
             return Ok(Moment {
-                time_zone: Utc,
-                date_time: Utc::now().with_timezone(&Utc.fix()),
-                locale: LOCALE_EN_US.clone(),
+                date_time: Utc::now(),
+                locale: default_locale(),
+                disambiguation: Disambiguation::default(),
             });
         }
 
@@ -109,75 +231,470 @@ impl Moment<Utc> {
     }
 }
 
+impl Moment<Tz> {
+    /// The current time in the named zone, like moment-timezone's `moment.tz(name)`.
+    pub fn new(name: &str) -> Result<Moment<Tz>, MomentError> {
+        Ok(Moment {
+            date_time: Utc::now().with_timezone(&Tz::from_name(name)?),
+            locale: default_locale(),
+            disambiguation: Disambiguation::default(),
+        })
+    }
+
+    /// Parses `date` as a wall-clock time in the named zone, like moment-timezone's
+    /// `moment.tz(date, format, name)`. If `date` contains an offset, that instant is converted to
+    /// the zone instead.
+    pub fn parse_in<T: Into<String>>(
+        date: T,
+        format: T,
+        name: &str,
+    ) -> Result<Moment<Tz>, MomentError> {
+        Moment::<Tz>::parse_in_with_locale(date, format, name, default_locale())
+    }
+
+    pub fn parse_in_with_locale<T: Into<String>, L: Into<Arc<Locale>>>(
+        date: T,
+        format: T,
+        name: &str,
+        locale: L,
+    ) -> Result<Moment<Tz>, MomentError> {
+        let tz = Tz::from_name(name)?;
+        let locale = locale.into();
+        let date_time =
+            locale.parse_in(&date.into(), &format.into(), &tz, Disambiguation::default())?;
+        Ok(Moment {
+            date_time,
+            locale,
+            disambiguation: Disambiguation::default(),
+        })
+    }
+
+    /// The changes of offset in this moment's zone from this moment until `end`, such as to show
+    /// that clocks change in 3 days.
+    pub fn transitions_until<U: TimeZone + Debug>(&self, end: &Moment<U>) -> Vec<Transition> {
+        self.timezone().transitions(&self.date_time, &end.date_time)
+    }
+}
+
+impl Moment<PosixTz> {
+    /// The current time in the zone described by the `TZ` environment variable. Unlike
+    /// `Moment::<Local>::new()`, this doesn't need zoneinfo files, so it gives the right local time
+    /// in minimal containers.
+    pub fn new() -> Result<Moment<PosixTz>, MomentError> {
+        Ok(Moment {
+            date_time: Utc::now().with_timezone(&PosixTz::from_env()?),
+            locale: default_locale(),
+            disambiguation: Disambiguation::default(),
+        })
+    }
+}
+
 impl<T: TimeZone + Debug> Moment<T> {
-    pub fn locale(self, locale: Locale) -> Moment<T> {
-        let mut moment = self.clone();
-        moment.locale = locale;
-        moment
+    /// Reads `local` as a wall-clock time in `time_zone`. `disambiguation` decides what happens
+    /// when the time is skipped or repeated, and is kept for later arithmetic, setters and
+    /// `start_of`.
+    pub fn from_local(
+        local: &NaiveDateTime,
+        time_zone: &T,
+        disambiguation: Disambiguation,
+    ) -> Result<Moment<T>, MomentError> {
+        Ok(Moment {
+            date_time: from_local(time_zone, local, disambiguation)?,
+            locale: default_locale(),
+            disambiguation,
+        })
+    }
+
+    pub fn time_zone(&self) -> T {
+        self.timezone()
     }
 
-    fn add_subtract(self, duration: Duration, is_adding: bool) -> Moment<T> {
-        let mut moment = self.clone();
-        moment.date_time = if is_adding {
-            self.checked_add_signed(duration).unwrap()
+    /// The same instant in the named zone, like moment-timezone's `moment.tz(name)` on an
+    /// existing moment.
+    pub fn tz(self, name: &str) -> Result<Moment<Tz>, MomentError> {
+        Ok(self.with_time_zone(&Tz::from_name(name)?))
+    }
+
+    /// The same instant in the zone for a Windows time zone ID such as `Pacific Standard Time`,
+    /// optionally in a particular territory. See [`Tz::from_windows_name`].
+    pub fn windows_tz(
+        self,
+        name: &str,
+        territory: Option<&str>,
+    ) -> Result<Moment<Tz>, MomentError> {
+        Ok(self.with_time_zone(&Tz::from_windows_name(name, territory)?))
+    }
+
+    /// The same instant in any other time zone, such as a [`TzifZone`](crate::tz::TzifZone).
+    pub fn with_time_zone<U: TimeZone + Debug>(self, time_zone: &U) -> Moment<U> {
+        Moment {
+            date_time: self.date_time.with_timezone(time_zone),
+            locale: self.locale,
+            disambiguation: self.disambiguation,
+        }
+    }
+
+    /// Switches to a fixed offset, like Moment.js's `utcOffset`. As in Moment.js, a number of
+    /// minutes between -16 and 16 is read as hours, and the last offset in a string is used. With
+    /// `keep_local_time` the wall-clock time stays the same and the instant changes; otherwise the
+    /// instant stays the same.
+    pub fn utc_offset<O: Into<UtcOffset>>(
+        self,
+        offset: O,
+        keep_local_time: bool,
+    ) -> Result<Moment<FixedOffset>, MomentError> {
+        let offset_seconds = match offset.into() {
+            UtcOffset::Minutes(minutes) if minutes.abs() < 16 => minutes * 3600,
+            UtcOffset::Minutes(minutes) => minutes.checked_mul(60).ok_or(MomentError::Overflow)?,
+            UtcOffset::String(input) => {
+                parse_utc_offset(&input).ok_or_else(|| MomentError::Parse {
+                    input,
+                    format: Some(String::from("Z")),
+                    offset: 0,
+                })?
+            }
+        };
+        let offset = FixedOffset::east_opt(offset_seconds).ok_or(MomentError::OutOfRange {
+            component: "offset",
+            value: i64::from(offset_seconds),
+        })?;
+        let date_time = if keep_local_time {
+            offset
+                .from_local_datetime(&self.naive_local())
+                .single()
+                .ok_or(MomentError::Overflow)?
         } else {
-            self.checked_sub_signed(duration).unwrap()
+            self.date_time.with_timezone(&offset)
         };
+        Ok(Moment {
+            date_time,
+            locale: self.locale,
+            disambiguation: self.disambiguation,
+        })
+    }
+
+    /// The same instant in each of the named zones, in order, keeping this moment's locale, as
+    /// for a world clock.
+    pub fn in_time_zones<S: AsRef<str>>(
+        &self,
+        names: &[S],
+    ) -> Result<Vec<Moment<Tz>>, MomentError> {
+        names
+            .iter()
+            .map(|name| self.clone().tz(name.as_ref()))
+            .collect()
+    }
+
+    /// Formats the same instant in each of the named zones with one format string, which is
+    /// compiled once with this moment's locale.
+    pub fn format_in_time_zones<S: AsRef<str>>(
+        &self,
+        names: &[S],
+        format: &str,
+    ) -> Result<Vec<String>, MomentError> {
+        let format = self.locale.compile_format(format);
+        Ok(self
+            .in_time_zones(names)?
+            .iter()
+            .map(|moment| moment.format_compiled(&format))
+            .collect())
+    }
+
+    pub fn locale<L: Into<Arc<Locale>>>(self, locale: L) -> Moment<T> {
+        Moment {
+            locale: locale.into(),
+            ..self
+        }
+    }
+
+    /// Sets the locale to the registered locale for a BCP 47 tag such as `en-GB`, falling back to
+    /// shorter tags (`en-GB` to `en`) when there is no exact match.
+    pub fn locale_by_name(self, tag: &str) -> Result<Moment<T>, MomentError> {
+        let locale = find_locale(tag).ok_or_else(|| MomentError::UnknownLocale(tag.to_string()))?;
+        Ok(self.locale(locale))
+    }
+
+    /// The name of the locale this moment formats with, such as `en-gb`.
+    pub fn locale_name(&self) -> &str {
+        &self.locale.name
+    }
+
+    /// The locale this moment formats with, like Moment.js's `localeData`.
+    pub fn locale_data(&self) -> &Locale {
+        &self.locale
+    }
+
+    /// Sets how wall-clock times that the time zone skips or repeats are read by later
+    /// arithmetic, setters and `start_of`.
+    pub fn disambiguation(self, disambiguation: Disambiguation) -> Moment<T> {
+        let mut moment = self;
+        moment.disambiguation = disambiguation;
         moment
     }
 
+    fn with_naive_local(self, local: &NaiveDateTime) -> Result<Moment<T>, MomentError> {
+        let mut moment = self;
+        moment.date_time = from_local(&moment.timezone(), local, moment.disambiguation)?;
+        Ok(moment)
+    }
+
+    // Like Moment.js, months (and years) are applied first and clamped to the end of the month,
+    // then days (and weeks) on the wall clock, and finally the fixed-length time as an absolute
+    // amount. The offset is recomputed from the time zone afterwards, so adding a day across a
+    // daylight saving time change keeps the wall-clock time. A wall-clock time that is skipped or
+    // repeated is read using the moment's `Disambiguation`.
+    fn add_subtract(
+        self,
+        duration: MomentDuration,
+        is_adding: bool,
+    ) -> Result<Moment<T>, MomentError> {
+        let sign = if is_adding { 1 } else { -1 };
+        let months = duration
+            .total_months()?
+            .checked_mul(sign)
+            .ok_or(MomentError::Overflow)?;
+        let days = duration
+            .total_days()?
+            .checked_mul(sign)
+            .ok_or(MomentError::Overflow)?;
+        let time = if is_adding {
+            duration.time
+        } else {
+            -duration.time
+        };
+
+        let mut moment = self;
+        if months != 0 || days != 0 {
+            let mut naive_date_time = moment.naive_local();
+            if months != 0 {
+                let months_to_add =
+                    Months::new(u32::try_from(months.abs()).map_err(|_| MomentError::Overflow)?);
+                naive_date_time = if months > 0 {
+                    naive_date_time.checked_add_months(months_to_add)
+                } else {
+                    naive_date_time.checked_sub_months(months_to_add)
+                }
+                .ok_or(MomentError::Overflow)?;
+            }
+            if days != 0 {
+                naive_date_time = naive_date_time
+                    .checked_add_signed(Duration::try_days(days).ok_or(MomentError::Overflow)?)
+                    .ok_or(MomentError::Overflow)?;
+            }
+            moment = moment.with_naive_local(&naive_date_time)?;
+        }
+
+        moment.date_time = moment
+            .date_time
+            .checked_add_signed(time)
+            .ok_or(MomentError::Overflow)?;
+        Ok(moment)
+    }
+
+    pub fn checked_add<D: Into<MomentDuration>>(
+        self,
+        duration: D,
+    ) -> Result<Moment<T>, MomentError> {
+        self.add_subtract(duration.into(), true)
+    }
+
+    pub fn checked_subtract<D: Into<MomentDuration>>(
+        self,
+        duration: D,
+    ) -> Result<Moment<T>, MomentError> {
+        self.add_subtract(duration.into(), false)
+    }
+
     // We want this to emultate Moment.js's API
     #[allow(clippy::should_implement_trait)]
-    pub fn add(self, duration: Duration) -> Moment<T> {
-        self.add_subtract(duration, true)
+    pub fn add<D: Into<MomentDuration>>(self, duration: D) -> Moment<T> {
+        self.checked_add(duration).unwrap()
     }
 
-    pub fn subtract(self, duration: Duration) -> Moment<T> {
-        self.add_subtract(duration, false)
+    pub fn subtract<D: Into<MomentDuration>>(self, duration: D) -> Moment<T> {
+        self.checked_subtract(duration).unwrap()
     }
 
-    pub fn start_of(self, unit: UnitOfTime) -> Moment<T> {
-        let mut moment = self.clone();
-        moment.date_time = match unit {
-            UnitOfTime::Nanosecond => self.date_time,
-            UnitOfTime::Microsecond => self.with_nanosecond(0).unwrap(),
-            UnitOfTime::Millisecond => self
-                .start_of(UnitOfTime::Microsecond)
-                .with_nanosecond(1000)
-                .unwrap(),
-            UnitOfTime::Second => self
-                .start_of(UnitOfTime::Millisecond)
-                .with_nanosecond(1_000_000)
-                .unwrap(),
-            UnitOfTime::Minute => self.start_of(UnitOfTime::Second).with_second(0).unwrap(),
-            UnitOfTime::Hour => self.start_of(UnitOfTime::Minute).with_minute(0).unwrap(),
-            UnitOfTime::Day => self.start_of(UnitOfTime::Hour).with_hour(0).unwrap(),
-            UnitOfTime::Week => {
-                let new_day = self.day0() - self.locale_aware_day_of_week();
-                self.start_of(UnitOfTime::Day).with_day0(new_day).unwrap()
+    /// Sets a component of the wall-clock time, like Moment.js's `set`. As in Moment.js, months
+    /// are counted from `0`, `UnitOfTime::Day` sets the day of the month, and setting the month,
+    /// quarter or year clamps the day to the end of the month. A value outside of the unit's range
+    /// is an error.
+    pub fn checked_set(self, unit: UnitOfTime, value: i64) -> Result<Moment<T>, MomentError> {
+        let local = self.naive_local();
+        let nanosecond = i64::from(local.nanosecond());
+        let with_date = |year: i32, month0: i64| {
+            let month = u32::try_from(month0 + 1).ok()?;
+            NaiveDate::from_ymd_opt(year, month, 1)?
+                .with_day(local.day().min(days_in_month(year, month)?))
+                .map(|date| date.and_time(local.time()))
+        };
+        let new_local = match unit {
+            UnitOfTime::Nanosecond if (0..1_000_000_000).contains(&value) => u32::try_from(value)
+                .ok()
+                .and_then(|value| local.with_nanosecond(value)),
+            UnitOfTime::Microsecond if (0..1_000_000).contains(&value) => {
+                u32::try_from(value * 1000 + nanosecond % 1000)
+                    .ok()
+                    .and_then(|value| local.with_nanosecond(value))
             }
-            UnitOfTime::IsoWeek => {
-                let new_day = self.day0() - self.weekday().num_days_from_monday();
-                self.start_of(UnitOfTime::Day).with_day0(new_day).unwrap()
+            UnitOfTime::Millisecond if (0..1000).contains(&value) => {
+                u32::try_from(value * 1_000_000 + nanosecond % 1_000_000)
+                    .ok()
+                    .and_then(|value| local.with_nanosecond(value))
             }
-            UnitOfTime::Quarter => {
-                let new_month = ((self.month() as f32 / 3_f32).floor() * 3_f32) as u32;
-                self.start_of(UnitOfTime::Month)
-                    .with_month(new_month)
-                    .unwrap()
+            UnitOfTime::Second => u32::try_from(value)
+                .ok()
+                .and_then(|value| local.with_second(value)),
+            UnitOfTime::Minute => u32::try_from(value)
+                .ok()
+                .and_then(|value| local.with_minute(value)),
+            UnitOfTime::Hour => u32::try_from(value)
+                .ok()
+                .and_then(|value| local.with_hour(value)),
+            UnitOfTime::Day => u32::try_from(value)
+                .ok()
+                .and_then(|value| local.with_day(value)),
+            UnitOfTime::Week | UnitOfTime::IsoWeek if (1..=53).contains(&value) => {
+                let week = if unit == UnitOfTime::Week {
+                    self.locale_aware_week_of_year().0
+                } else {
+                    self.iso_week().week()
+                };
+                Duration::try_weeks(value - i64::from(week))
+                    .and_then(|weeks| local.checked_add_signed(weeks))
             }
-            UnitOfTime::Month => self.start_of(UnitOfTime::Day).with_day0(0).unwrap(),
-            UnitOfTime::Year => self.start_of(UnitOfTime::Month).with_month0(0).unwrap(),
+            UnitOfTime::Month => with_date(local.year(), value),
+            UnitOfTime::Quarter if (1..=4).contains(&value) => with_date(
+                local.year(),
+                (value - 1) * 3 + i64::from(local.month0() % 3),
+            ),
+            UnitOfTime::Year => i32::try_from(value)
+                .ok()
+                .and_then(|year| with_date(year, i64::from(local.month0()))),
+            _ => None,
+        }
+        .ok_or(MomentError::OutOfRange {
+            component: match unit {
+                UnitOfTime::Nanosecond => "nanosecond",
+                UnitOfTime::Microsecond => "microsecond",
+                UnitOfTime::Millisecond => "millisecond",
+                UnitOfTime::Second => "second",
+                UnitOfTime::Minute => "minute",
+                UnitOfTime::Hour => "hour",
+                UnitOfTime::Day => "day",
+                UnitOfTime::IsoWeek => "ISO week",
+                UnitOfTime::Week => "week",
+                UnitOfTime::Quarter => "quarter",
+                UnitOfTime::Month => "month",
+                UnitOfTime::Year => "year",
+            },
+            value,
+        })?;
+        self.with_naive_local(&new_local)
+    }
+
+    pub fn set(self, unit: UnitOfTime, value: i64) -> Moment<T> {
+        self.checked_set(unit, value).unwrap()
+    }
+
+    // Units shorter than a day are truncated in absolute time, so the offset is kept when the
+    // hour repeats. Longer units start at midnight on the wall clock, which is read using the
+    // moment's `Disambiguation` for zones where midnight can be skipped.
+    pub fn checked_start_of(self, unit: UnitOfTime) -> Result<Moment<T>, MomentError> {
+        let local = self.naive_local();
+        let date = local.date();
+        let nanoseconds_per_unit = match unit {
+            UnitOfTime::Nanosecond => return Ok(self),
+            UnitOfTime::Microsecond => Some(1000),
+            UnitOfTime::Millisecond => Some(1_000_000),
+            UnitOfTime::Second => Some(1_000_000_000),
+            UnitOfTime::Minute => Some(60_000_000_000),
+            UnitOfTime::Hour => Some(3_600_000_000_000),
+            _ => None,
         };
-        moment
+        if let Some(nanoseconds_per_unit) = nanoseconds_per_unit {
+            let elapsed = local
+                .signed_duration_since(date.and_time(NaiveTime::MIN))
+                .num_nanoseconds()
+                .ok_or(MomentError::Overflow)?;
+            let mut moment = self;
+            moment.date_time = moment
+                .date_time
+                .checked_sub_signed(Duration::nanoseconds(elapsed % nanoseconds_per_unit))
+                .ok_or(MomentError::Overflow)?;
+            return Ok(moment);
+        }
+
+        let start = self.start_date_of(unit).ok_or(MomentError::Overflow)?;
+        self.with_naive_local(&start.and_time(NaiveTime::MIN))
+    }
+
+    // The calendar date on which the day, week, month, quarter or year containing this moment
+    // starts.
+    fn start_date_of(&self, unit: UnitOfTime) -> Option<NaiveDate> {
+        let date = self.naive_local().date();
+        match unit {
+            UnitOfTime::Week => {
+                date.checked_sub_signed(Duration::days(i64::from(self.locale_aware_day_of_week())))
+            }
+            UnitOfTime::IsoWeek => date.checked_sub_signed(Duration::days(i64::from(
+                self.weekday().num_days_from_monday(),
+            ))),
+            UnitOfTime::Month => date.with_day(1),
+            UnitOfTime::Quarter => {
+                NaiveDate::from_ymd_opt(date.year(), date.month0() - date.month0() % 3 + 1, 1)
+            }
+            UnitOfTime::Year => NaiveDate::from_ymd_opt(date.year(), 1, 1),
+            _ => Some(date),
+        }
+    }
+
+    pub fn start_of(self, unit: UnitOfTime) -> Moment<T> {
+        self.checked_start_of(unit).unwrap()
+    }
+
+    // Units shorter than a day end one unit after they start in absolute time. Longer units end
+    // just before midnight on the wall clock at the start of the next unit, so a skipped midnight
+    // at either end doesn't move the end by the length of the gap.
+    pub fn checked_end_of(self, unit: UnitOfTime) -> Result<Moment<T>, MomentError> {
+        let next_start = match unit {
+            UnitOfTime::Day => self.start_date_of(unit).and_then(|date| date.succ_opt()),
+            UnitOfTime::Week | UnitOfTime::IsoWeek => self
+                .start_date_of(unit)
+                .and_then(|date| date.checked_add_signed(Duration::days(7))),
+            UnitOfTime::Month => self
+                .start_date_of(unit)
+                .and_then(|date| date.checked_add_months(Months::new(1))),
+            UnitOfTime::Quarter => self
+                .start_date_of(unit)
+                .and_then(|date| date.checked_add_months(Months::new(3))),
+            UnitOfTime::Year => self
+                .start_date_of(unit)
+                .and_then(|date| date.checked_add_months(Months::new(12))),
+            _ => {
+                return self
+                    .checked_start_of(unit)?
+                    .checked_add(MomentDuration::checked_from_unit(1, unit)?)?
+                    .checked_subtract(Duration::nanoseconds(1));
+            }
+        }
+        .ok_or(MomentError::Overflow)?;
+        // The next unit starts at the instant its first day actually starts, so a skipped
+        // midnight is shifted forward whatever the moment's `Disambiguation`.
+        let next_start = from_local(
+            &self.timezone(),
+            &next_start.and_time(NaiveTime::MIN),
+            Disambiguation::Compatible,
+        )?;
+        let mut moment = self;
+        moment.date_time = next_start
+            .checked_sub_signed(Duration::nanoseconds(1))
+            .ok_or(MomentError::Overflow)?;
+        Ok(moment)
     }
 
     pub fn end_of(self, unit: UnitOfTime) -> Moment<T> {
-        let mut durations = HashSet::new();
-        durations.insert((1, UnitOfTime::Nanosecond));
-        self.start_of(unit)
-            .add(duration(durations))
-            .subtract(Duration::nanoseconds(1))
+        self.checked_end_of(unit).unwrap()
     }
 
     pub(crate) fn locale_aware_day_of_week(&self) -> u32 {
@@ -200,8 +717,8 @@ impl<T: TimeZone + Debug> Moment<T> {
     fn first_week_offset(&self, year: i32) -> u32 {
         let first_week_day = 7_i32 + self.locale.week.dow as i32 - self.locale.week.doy as i32;
         let first_week_day_local_weekday = (7_i32
-            + Utc
-                .ymd(year, 0, first_week_day as u32)
+            + NaiveDate::from_ymd_opt(year, 1, first_week_day as u32)
+                .unwrap()
                 .weekday()
                 .num_days_from_sunday() as i32
             - self.locale.week.dow as i32)
@@ -231,8 +748,88 @@ impl<T: TimeZone + Debug> Moment<T> {
         (week, self.year())
     }
 
-    pub fn format(&self, format: String) -> String {
-        self.locale.format(&self, format)
+    /// Whether daylight saving time is in effect, like Moment.js's `isDST`.
+    pub fn is_dst(&self) -> bool
+    where
+        T::Offset: OffsetName,
+    {
+        OffsetName::is_dst(self.offset())
+    }
+
+    pub fn checked_format(&self, format: String) -> Result<String, MomentError>
+    where
+        T::Offset: OffsetName,
+    {
+        self.locale.checked_format(self, format)
+    }
+
+    pub fn format(&self, format: String) -> String
+    where
+        T::Offset: OffsetName,
+    {
+        self.locale.format(self, format)
+    }
+
+    /// Formats with a format string from [`Locale::compile_format`], which is faster when
+    /// formatting many moments.
+    pub fn format_compiled(&self, format: &CompiledFormat) -> String
+    where
+        T::Offset: OffsetName,
+    {
+        self.locale.format_compiled(self, format)
+    }
+
+    pub fn checked_format_compiled(&self, format: &CompiledFormat) -> Result<String, MomentError>
+    where
+        T::Offset: OffsetName,
+    {
+        self.locale.checked_format_compiled(self, format)
+    }
+
+    /// The time from `other` to this moment in words, such as "in 3 hours" or "3 hours ago".
+    pub fn from<U: TimeZone + Debug>(
+        &self,
+        other: &Moment<U>,
+        without_suffix: bool,
+        config: Option<&RelativeTimeConfig>,
+    ) -> String {
+        self.locale.humanize(
+            &moments_difference(&other.fixed_offset(), &self.fixed_offset()),
+            without_suffix,
+            config,
+        )
+    }
+
+    pub fn from_now(&self, without_suffix: bool, config: Option<&RelativeTimeConfig>) -> String {
+        let now = Utc::now().with_timezone(&self.offset().fix());
+        self.locale.humanize(
+            &moments_difference(&now, &self.fixed_offset()),
+            without_suffix,
+            config,
+        )
+    }
+
+    /// The time from this moment to `other` in words, the inverse of [`Moment::from`].
+    pub fn to<U: TimeZone + Debug>(
+        &self,
+        other: &Moment<U>,
+        without_suffix: bool,
+        config: Option<&RelativeTimeConfig>,
+    ) -> String {
+        self.locale.humanize(
+            &moments_difference(&self.fixed_offset(), &other.fixed_offset()),
+            without_suffix,
+            config,
+        )
+    }
+
+    pub fn to_now(&self, without_suffix: bool, config: Option<&RelativeTimeConfig>) -> String {
+        let now = Utc::now().with_timezone(&self.offset().fix());
+        self.locale.humanize(
+            &moments_difference(&self.fixed_offset(), &now),
+            without_suffix,
+            config,
+        )
     }
 
     pub fn calendar(
@@ -240,8 +837,11 @@ impl<T: TimeZone + Debug> Moment<T> {
         reference_moment: Moment<T>,
         formats: Option<&Calendar>,
         calendar_format: Option<CalendarFormatFn<T>>,
-    ) -> String {
+    ) -> String
+    where
+        T::Offset: OffsetName,
+    {
         self.locale
-            .calendar(&self, reference_moment, formats, calendar_format)
+            .calendar(self, reference_moment, formats, calendar_format)
     }
 }
@@ -0,0 +1,118 @@
+use lazy_static::lazy_static;
+use std::{collections::BTreeMap, sync::RwLock};
+
+lazy_static! {
+    static ref TIME_ZONE_ABBREVIATIONS: RwLock<TimeZoneAbbreviations> =
+        RwLock::new(TimeZoneAbbreviations::default());
+}
+
+const SECONDS_PER_HOUR: i32 = 3600;
+
+// Abbreviations that several zones share resolve to the most common meaning: `CST` is US Central
+// Standard Time rather than China Standard Time, `IST` is India Standard Time rather than Irish or
+// Israel Standard Time, and `BST` is British Summer Time.
+const DEFAULT_ABBREVIATIONS: [(&str, i32); 44] = [
+    ("UT", 0),
+    ("UTC", 0),
+    ("GMT", 0),
+    ("Z", 0),
+    ("EST", -5 * SECONDS_PER_HOUR),
+    ("EDT", -4 * SECONDS_PER_HOUR),
+    ("CST", -6 * SECONDS_PER_HOUR),
+    ("CDT", -5 * SECONDS_PER_HOUR),
+    ("MST", -7 * SECONDS_PER_HOUR),
+    ("MDT", -6 * SECONDS_PER_HOUR),
+    ("PST", -8 * SECONDS_PER_HOUR),
+    ("PDT", -7 * SECONDS_PER_HOUR),
+    ("AKST", -9 * SECONDS_PER_HOUR),
+    ("AKDT", -8 * SECONDS_PER_HOUR),
+    ("HST", -10 * SECONDS_PER_HOUR),
+    ("HDT", -9 * SECONDS_PER_HOUR),
+    ("AST", -4 * SECONDS_PER_HOUR),
+    ("ADT", -3 * SECONDS_PER_HOUR),
+    ("NST", -3 * SECONDS_PER_HOUR - 1800),
+    ("NDT", -2 * SECONDS_PER_HOUR - 1800),
+    ("WET", 0),
+    ("WEST", SECONDS_PER_HOUR),
+    ("BST", SECONDS_PER_HOUR),
+    ("CET", SECONDS_PER_HOUR),
+    ("CEST", 2 * SECONDS_PER_HOUR),
+    ("EET", 2 * SECONDS_PER_HOUR),
+    ("EEST", 3 * SECONDS_PER_HOUR),
+    ("MSK", 3 * SECONDS_PER_HOUR),
+    ("SAST", 2 * SECONDS_PER_HOUR),
+    ("IST", 5 * SECONDS_PER_HOUR + 1800),
+    ("PKT", 5 * SECONDS_PER_HOUR),
+    ("WIB", 7 * SECONDS_PER_HOUR),
+    ("HKT", 8 * SECONDS_PER_HOUR),
+    ("SGT", 8 * SECONDS_PER_HOUR),
+    ("AWST", 8 * SECONDS_PER_HOUR),
+    ("JST", 9 * SECONDS_PER_HOUR),
+    ("KST", 9 * SECONDS_PER_HOUR),
+    ("ACST", 9 * SECONDS_PER_HOUR + 1800),
+    ("ACDT", 10 * SECONDS_PER_HOUR + 1800),
+    ("AEST", 10 * SECONDS_PER_HOUR),
+    ("AEDT", 11 * SECONDS_PER_HOUR),
+    ("NZST", 12 * SECONDS_PER_HOUR),
+    ("NZDT", 13 * SECONDS_PER_HOUR),
+    ("ChST", 10 * SECONDS_PER_HOUR),
+];
+
+/// The offsets, in seconds east of UTC, that the `z` parsing token reads time zone abbreviations
+/// as. Abbreviations are matched case-insensitively.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimeZoneAbbreviations {
+    offsets: BTreeMap<String, i32>,
+}
+
+impl TimeZoneAbbreviations {
+    /// A table without any abbreviations.
+    pub fn empty() -> TimeZoneAbbreviations {
+        TimeZoneAbbreviations {
+            offsets: BTreeMap::new(),
+        }
+    }
+
+    /// Adds or replaces an abbreviation, such as `("IST", 3600)` to read `IST` as Irish Standard
+    /// Time.
+    pub fn insert(&mut self, abbreviation: &str, utc_offset: i32) -> Option<i32> {
+        self.offsets.insert(abbreviation.to_uppercase(), utc_offset)
+    }
+
+    pub fn remove(&mut self, abbreviation: &str) -> Option<i32> {
+        self.offsets.remove(&abbreviation.to_uppercase())
+    }
+
+    pub fn get(&self, abbreviation: &str) -> Option<i32> {
+        self.offsets.get(&abbreviation.to_uppercase()).copied()
+    }
+
+    pub fn abbreviations(&self) -> impl Iterator<Item = &str> {
+        self.offsets.keys().map(String::as_str)
+    }
+}
+
+impl Default for TimeZoneAbbreviations {
+    fn default() -> TimeZoneAbbreviations {
+        let mut abbreviations = TimeZoneAbbreviations::empty();
+        for (abbreviation, utc_offset) in DEFAULT_ABBREVIATIONS.iter() {
+            abbreviations.insert(abbreviation, *utc_offset);
+        }
+        abbreviations
+    }
+}
+
+/// The table used when parsing the `z` token.
+pub fn time_zone_abbreviations() -> TimeZoneAbbreviations {
+    TIME_ZONE_ABBREVIATIONS.read().unwrap().clone()
+}
+
+/// Calls `f` with the table used when parsing the `z` token, borrowed under the read lock.
+pub(crate) fn with_time_zone_abbreviations<T, F: FnOnce(&TimeZoneAbbreviations) -> T>(f: F) -> T {
+    f(&TIME_ZONE_ABBREVIATIONS.read().unwrap())
+}
+
+/// Replaces the table used when parsing the `z` token.
+pub fn set_time_zone_abbreviations(abbreviations: TimeZoneAbbreviations) {
+    *TIME_ZONE_ABBREVIATIONS.write().unwrap() = abbreviations;
+}
@@ -0,0 +1,146 @@
+use super::Tz;
+
+// A subset of CLDR's metaZones.xml: zones that share long names, such as "Eastern Standard
+// Time", are grouped under one metazone. `UTC` isn't a CLDR metazone but is named the same way.
+const METAZONES: [(&str, &str); 121] = [
+    ("America/New_York", "America_Eastern"),
+    ("America/Detroit", "America_Eastern"),
+    ("America/Indiana/Indianapolis", "America_Eastern"),
+    ("America/Indiana/Marengo", "America_Eastern"),
+    ("America/Indiana/Petersburg", "America_Eastern"),
+    ("America/Indiana/Vevay", "America_Eastern"),
+    ("America/Indiana/Vincennes", "America_Eastern"),
+    ("America/Indiana/Winamac", "America_Eastern"),
+    ("America/Kentucky/Louisville", "America_Eastern"),
+    ("America/Kentucky/Monticello", "America_Eastern"),
+    ("America/Toronto", "America_Eastern"),
+    ("America/Iqaluit", "America_Eastern"),
+    ("America/Nassau", "America_Eastern"),
+    ("America/Panama", "America_Eastern"),
+    ("America/Jamaica", "America_Eastern"),
+    ("America/Cancun", "America_Eastern"),
+    ("America/Port-au-Prince", "America_Eastern"),
+    ("America/Grand_Turk", "America_Eastern"),
+    ("America/Cayman", "America_Eastern"),
+    ("America/Chicago", "America_Central"),
+    ("America/Indiana/Knox", "America_Central"),
+    ("America/Indiana/Tell_City", "America_Central"),
+    ("America/Menominee", "America_Central"),
+    ("America/North_Dakota/Beulah", "America_Central"),
+    ("America/North_Dakota/Center", "America_Central"),
+    ("America/North_Dakota/New_Salem", "America_Central"),
+    ("America/Winnipeg", "America_Central"),
+    ("America/Rankin_Inlet", "America_Central"),
+    ("America/Resolute", "America_Central"),
+    ("America/Regina", "America_Central"),
+    ("America/Swift_Current", "America_Central"),
+    ("America/Mexico_City", "America_Central"),
+    ("America/Monterrey", "America_Central"),
+    ("America/Merida", "America_Central"),
+    ("America/Matamoros", "America_Central"),
+    ("America/Bahia_Banderas", "America_Central"),
+    ("America/Chihuahua", "America_Central"),
+    ("America/Ojinaga", "America_Central"),
+    ("America/Belize", "America_Central"),
+    ("America/Costa_Rica", "America_Central"),
+    ("America/El_Salvador", "America_Central"),
+    ("America/Guatemala", "America_Central"),
+    ("America/Tegucigalpa", "America_Central"),
+    ("America/Managua", "America_Central"),
+    ("America/Denver", "America_Mountain"),
+    ("America/Boise", "America_Mountain"),
+    ("America/Phoenix", "America_Mountain"),
+    ("America/Edmonton", "America_Mountain"),
+    ("America/Cambridge_Bay", "America_Mountain"),
+    ("America/Inuvik", "America_Mountain"),
+    ("America/Creston", "America_Mountain"),
+    ("America/Dawson_Creek", "America_Mountain"),
+    ("America/Fort_Nelson", "America_Mountain"),
+    ("America/Ciudad_Juarez", "America_Mountain"),
+    ("America/Los_Angeles", "America_Pacific"),
+    ("America/Vancouver", "America_Pacific"),
+    ("America/Tijuana", "America_Pacific"),
+    ("America/Anchorage", "Alaska"),
+    ("America/Juneau", "Alaska"),
+    ("America/Sitka", "Alaska"),
+    ("America/Metlakatla", "Alaska"),
+    ("America/Yakutat", "Alaska"),
+    ("America/Nome", "Alaska"),
+    ("America/Adak", "Hawaii_Aleutian"),
+    ("Pacific/Honolulu", "Hawaii_Aleutian"),
+    ("America/Halifax", "Atlantic"),
+    ("America/Glace_Bay", "Atlantic"),
+    ("America/Goose_Bay", "Atlantic"),
+    ("America/Moncton", "Atlantic"),
+    ("America/Thule", "Atlantic"),
+    ("America/Puerto_Rico", "Atlantic"),
+    ("America/Barbados", "Atlantic"),
+    ("America/Martinique", "Atlantic"),
+    ("America/Santo_Domingo", "Atlantic"),
+    ("Atlantic/Bermuda", "Atlantic"),
+    ("America/St_Johns", "Newfoundland"),
+    ("Europe/London", "GMT"),
+    ("Europe/Dublin", "GMT"),
+    ("Africa/Abidjan", "GMT"),
+    ("Africa/Bissau", "GMT"),
+    ("Africa/Monrovia", "GMT"),
+    ("Africa/Sao_Tome", "GMT"),
+    ("America/Danmarkshavn", "GMT"),
+    ("Atlantic/Reykjavik", "GMT"),
+    ("Europe/Lisbon", "Europe_Western"),
+    ("Atlantic/Canary", "Europe_Western"),
+    ("Atlantic/Faroe", "Europe_Western"),
+    ("Atlantic/Madeira", "Europe_Western"),
+    ("Europe/Paris", "Europe_Central"),
+    ("Europe/Berlin", "Europe_Central"),
+    ("Europe/Brussels", "Europe_Central"),
+    ("Europe/Madrid", "Europe_Central"),
+    ("Europe/Rome", "Europe_Central"),
+    ("Europe/Vienna", "Europe_Central"),
+    ("Europe/Zurich", "Europe_Central"),
+    ("Europe/Prague", "Europe_Central"),
+    ("Europe/Warsaw", "Europe_Central"),
+    ("Europe/Budapest", "Europe_Central"),
+    ("Europe/Belgrade", "Europe_Central"),
+    ("Europe/Tirane", "Europe_Central"),
+    ("Europe/Malta", "Europe_Central"),
+    ("Europe/Andorra", "Europe_Central"),
+    ("Europe/Gibraltar", "Europe_Central"),
+    ("Africa/Ceuta", "Europe_Central"),
+    ("Africa/Algiers", "Europe_Central"),
+    ("Africa/Tunis", "Europe_Central"),
+    ("Europe/Athens", "Europe_Eastern"),
+    ("Europe/Bucharest", "Europe_Eastern"),
+    ("Europe/Helsinki", "Europe_Eastern"),
+    ("Europe/Kyiv", "Europe_Eastern"),
+    ("Europe/Riga", "Europe_Eastern"),
+    ("Europe/Sofia", "Europe_Eastern"),
+    ("Europe/Tallinn", "Europe_Eastern"),
+    ("Europe/Vilnius", "Europe_Eastern"),
+    ("Europe/Chisinau", "Europe_Eastern"),
+    ("Europe/Kaliningrad", "Europe_Eastern"),
+    ("Asia/Nicosia", "Europe_Eastern"),
+    ("Africa/Cairo", "Europe_Eastern"),
+    ("Asia/Shanghai", "China"),
+    ("Asia/Macau", "China"),
+    ("Etc/UTC", "UTC"),
+];
+
+/// The CLDR metazone of a zone, such as `America_Eastern` for `America/New_York`, which locales
+/// use to share long names between zones. Links such as `US/Eastern` resolve to the metazone of
+/// their zone.
+pub fn metazone(zone: &str) -> Option<&'static str> {
+    let find = |zone: &str| {
+        METAZONES
+            .iter()
+            .find(|(name, _)| *name == zone)
+            .map(|(_, metazone)| *metazone)
+    };
+    find(zone).or_else(|| {
+        let tz = Tz::from_name(zone).ok()?;
+        METAZONES
+            .iter()
+            .find(|(name, _)| Tz::from_name(name).is_ok_and(|other| tz.is_same_zone(&other)))
+            .map(|(_, metazone)| *metazone)
+    })
+}
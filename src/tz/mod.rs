@@ -0,0 +1,150 @@
+mod abbreviations;
+mod metazones;
+mod named;
+mod posix;
+mod transitions;
+mod tzif;
+mod windows;
+
+pub use self::{abbreviations::*, metazones::*, named::*, posix::*, tzif::*};
+use crate::MomentError;
+use chrono::{DateTime, Duration, FixedOffset, LocalResult, NaiveDateTime, Offset, TimeZone, Utc};
+use std::{borrow::Cow, sync::Arc};
+
+/// The abbreviation and daylight saving time flag of an offset, such as `EST` or `BST`, and the
+/// name of its zone. This is what the `z` and `zz` formatting tokens print.
+pub trait OffsetName {
+    fn abbreviation(&self) -> Cow<'_, str>;
+
+    fn is_dst(&self) -> bool;
+
+    /// The IANA name of the zone, such as `America/New_York`, which `zz` looks long names up by.
+    fn zone_name(&self) -> Option<&str> {
+        None
+    }
+}
+
+impl OffsetName for TzOffset {
+    fn abbreviation(&self) -> Cow<'_, str> {
+        Cow::Borrowed(TzOffset::abbreviation(self))
+    }
+
+    fn is_dst(&self) -> bool {
+        TzOffset::is_dst(self)
+    }
+
+    fn zone_name(&self) -> Option<&str> {
+        Some(self.tz().name())
+    }
+}
+
+impl OffsetName for TzifOffset {
+    fn abbreviation(&self) -> Cow<'_, str> {
+        Cow::Borrowed(TzifOffset::abbreviation(self))
+    }
+
+    fn is_dst(&self) -> bool {
+        TzifOffset::is_dst(self)
+    }
+
+    fn zone_name(&self) -> Option<&str> {
+        Some(self.zone().name())
+    }
+}
+
+impl OffsetName for PosixOffset {
+    fn abbreviation(&self) -> Cow<'_, str> {
+        Cow::Borrowed(PosixOffset::abbreviation(self))
+    }
+
+    fn is_dst(&self) -> bool {
+        PosixOffset::is_dst(self)
+    }
+}
+
+impl OffsetName for Utc {
+    fn abbreviation(&self) -> Cow<'_, str> {
+        Cow::Borrowed("UTC")
+    }
+
+    fn is_dst(&self) -> bool {
+        false
+    }
+
+    fn zone_name(&self) -> Option<&str> {
+        Some("Etc/UTC")
+    }
+}
+
+/// A fixed offset has no abbreviation, so it is written like `+05:30`. This is also what
+/// `Moment<Local>` uses.
+impl OffsetName for FixedOffset {
+    fn abbreviation(&self) -> Cow<'_, str> {
+        let offset = self.local_minus_utc();
+        let sign = if offset < 0 { '-' } else { '+' };
+        let minutes = offset.abs() / 60;
+        Cow::Owned(format!("{}{:02}:{:02}", sign, minutes / 60, minutes % 60))
+    }
+
+    fn is_dst(&self) -> bool {
+        false
+    }
+}
+
+/// An offset from UTC with its abbreviation, for zones that are loaded at runtime.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct LocalTimeType {
+    pub(crate) utc_offset: i32,
+    pub(crate) is_dst: bool,
+    pub(crate) abbreviation: Arc<str>,
+}
+
+/// How to read a wall-clock time that a time zone skips or repeats around a daylight saving time
+/// change. The names follow the `disambiguation` option of JavaScript's Temporal.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash)]
+pub enum Disambiguation {
+    /// The earlier instant when the time repeats, and the time shifted forward by the length of
+    /// the gap when it is skipped. This is what Moment.js does, and the default.
+    #[default]
+    Compatible,
+    /// The earlier instant when the time repeats, and the time shifted backward by the length of
+    /// the gap when it is skipped.
+    Earlier,
+    /// The later instant when the time repeats, and the time shifted forward by the length of the
+    /// gap when it is skipped.
+    Later,
+    /// An error whenever the time is skipped or repeated.
+    Reject,
+}
+
+/// Reads `local` as a wall-clock time in `time_zone`, using `disambiguation` when the time is
+/// skipped or repeated.
+pub(crate) fn from_local<Z: TimeZone>(
+    time_zone: &Z,
+    local: &NaiveDateTime,
+    disambiguation: Disambiguation,
+) -> Result<DateTime<Z>, MomentError> {
+    // A skipped time is shifted forward by reading it with the offset from before the gap, and
+    // backward by reading it with the offset from after it.
+    let from_offset_around = |days: i64| {
+        local
+            .checked_add_signed(Duration::days(days))
+            .map(|around| time_zone.offset_from_utc_datetime(&around).fix())
+            .and_then(|offset| offset.from_local_datetime(local).single())
+            .map(|date_time| date_time.with_timezone(time_zone))
+            .ok_or(MomentError::Overflow)
+    };
+    match (time_zone.from_local_datetime(local), disambiguation) {
+        (LocalResult::Single(date_time), _) => Ok(date_time),
+        (LocalResult::Ambiguous(_, _), Disambiguation::Reject) => {
+            Err(MomentError::AmbiguousLocalTime(local.to_string()))
+        }
+        (LocalResult::Ambiguous(_, later), Disambiguation::Later) => Ok(later),
+        (LocalResult::Ambiguous(earlier, _), _) => Ok(earlier),
+        (LocalResult::None, Disambiguation::Reject) => {
+            Err(MomentError::NonexistentLocalTime(local.to_string()))
+        }
+        (LocalResult::None, Disambiguation::Earlier) => from_offset_around(1),
+        (LocalResult::None, _) => from_offset_around(-1),
+    }
+}
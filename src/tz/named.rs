@@ -0,0 +1,314 @@
+use super::{
+    transitions::{candidate_spans, local_result, span_at_utc},
+    LocalTimeType, PosixTz,
+};
+use crate::MomentError;
+use chrono::{DateTime, FixedOffset, LocalResult, NaiveDate, NaiveDateTime, Offset, TimeZone, Utc};
+use std::{
+    fmt,
+    hash::{Hash, Hasher},
+    str::FromStr,
+    sync::OnceLock,
+};
+
+// The embedded form of [`LocalTimeType`], with a static abbreviation.
+#[derive(Debug)]
+struct ZoneType {
+    utc_offset: i32,
+    is_dst: bool,
+    abbreviation: &'static str,
+}
+
+#[derive(Debug)]
+struct ZoneData {
+    transitions: &'static [i64],
+    spans: &'static [u8],
+    types: &'static [ZoneType],
+    // The POSIX TZ string for the zone's current rules, used after the last transition.
+    footer: Option<&'static str>,
+    posix_tz: OnceLock<PosixTz>,
+}
+
+// Generated by `build.rs` from the IANA tz database sources in `tzdata/`. Transitions are
+// precomputed up to the year 2100 and the footer of each zone is followed after that.
+include!(concat!(env!("OUT_DIR"), "/zones.rs"));
+
+/// A named time zone from the IANA tz database embedded in this crate, such as
+/// `America/New_York` or `Europe/London`. Links such as `US/Eastern` are accepted too.
+#[derive(Clone, Copy)]
+pub struct Tz {
+    name: &'static str,
+    data: &'static ZoneData,
+}
+
+impl Tz {
+    /// Looks up a zone by name. Like moment-timezone, an exact match is tried first and then a
+    /// case-insensitive one.
+    pub fn from_name(name: &str) -> Result<Tz, MomentError> {
+        let zone = match ZONES.binary_search_by(|(zone_name, _)| (*zone_name).cmp(name)) {
+            Ok(index) => Some(&ZONES[index]),
+            Err(_) => ZONES
+                .iter()
+                .find(|(zone_name, _)| zone_name.eq_ignore_ascii_case(name)),
+        };
+        zone.map(|(name, data)| Tz { name, data })
+            .ok_or_else(|| MomentError::UnknownTimeZone(String::from(name)))
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// The names of every zone and link in the embedded database, in alphabetical order.
+    pub fn names() -> impl Iterator<Item = &'static str> {
+        ZONES.iter().map(|(name, _)| *name)
+    }
+
+    /// The release of the tz database that was embedded, such as `2025b`.
+    pub fn tzdata_version() -> &'static str {
+        TZDATA_VERSION
+    }
+
+    /// The changes of offset, abbreviation or daylight saving time at or after `start` and before
+    /// `end`, oldest first. Historical changes are included, such as the switch from local mean
+    /// time (`LMT`) to standard time. The embedded transitions stop in 2100, and later ones are
+    /// computed from the zone's current rules.
+    pub fn transitions<A: TimeZone, B: TimeZone>(
+        &self,
+        start: &DateTime<A>,
+        end: &DateTime<B>,
+    ) -> Vec<Transition> {
+        let instant = |transition: i64| Utc.timestamp_opt(transition, 0).unwrap();
+        let transitions = self.data.transitions;
+        let first = transitions.partition_point(|transition| instant(*transition) < *start);
+        let mut result: Vec<Transition> = (first..transitions.len())
+            .take_while(|index| instant(transitions[*index]) < *end)
+            .filter(|index| self.data.spans[index + 1] != self.data.spans[*index])
+            .map(|index| Transition {
+                instant: instant(transitions[index]),
+                old_offset: self.offset_for_span(index),
+                new_offset: self.offset_for_span(index + 1),
+            })
+            .collect();
+
+        if let Some(footer) = self.footer() {
+            let after = transitions.last().map_or(start.timestamp() - 1, |last| {
+                (start.timestamp() - 1).max(*last)
+            });
+            result.extend(
+                footer
+                    .transitions_after(after)
+                    .take_while(|transition| {
+                        Utc.timestamp_opt(*transition, 0)
+                            .single()
+                            .is_some_and(|transition| transition < *end)
+                    })
+                    .map(|transition| Transition {
+                        instant: instant(transition),
+                        old_offset: self
+                            .offset_for_time_type(footer.time_type_at_utc(transition - 1)),
+                        new_offset: self.offset_for_time_type(footer.time_type_at_utc(transition)),
+                    })
+                    .filter(|transition| transition.old_offset != transition.new_offset),
+            );
+        }
+        result
+    }
+
+    /// Whether both are the same zone, even if one of them was looked up through a link.
+    pub(super) fn is_same_zone(&self, other: &Tz) -> bool {
+        std::ptr::eq(self.data, other.data)
+    }
+
+    /// The zone's current rules, which apply after the last embedded transition.
+    pub(super) fn footer(&self) -> Option<&'static PosixTz> {
+        let data = self.data;
+        let footer = data.footer?;
+        Some(
+            data.posix_tz
+                .get_or_init(|| PosixTz::parse(footer).expect("footers are generated by build.rs")),
+        )
+    }
+
+    fn is_after_last_transition(&self, utc_seconds: i64) -> bool {
+        self.data
+            .transitions
+            .last()
+            .is_none_or(|last| utc_seconds >= *last)
+    }
+
+    fn offset_for_time_type(&self, local_time_type: &'static LocalTimeType) -> TzOffset {
+        TzOffset {
+            tz: *self,
+            utc_offset: FixedOffset::east_opt(local_time_type.utc_offset).unwrap(),
+            is_dst: local_time_type.is_dst,
+            abbreviation: &local_time_type.abbreviation,
+        }
+    }
+
+    fn offset_for_span(&self, span: usize) -> TzOffset {
+        let zone_type = &self.data.types[usize::from(self.data.spans[span])];
+        TzOffset {
+            tz: *self,
+            utc_offset: FixedOffset::east_opt(zone_type.utc_offset).unwrap(),
+            is_dst: zone_type.is_dst,
+            abbreviation: zone_type.abbreviation,
+        }
+    }
+}
+
+impl PartialEq for Tz {
+    fn eq(&self, other: &Tz) -> bool {
+        self.name == other.name
+    }
+}
+
+impl Eq for Tz {}
+
+impl Hash for Tz {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.name.hash(state);
+    }
+}
+
+impl fmt::Debug for Tz {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+impl fmt::Display for Tz {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+impl FromStr for Tz {
+    type Err = MomentError;
+
+    fn from_str(name: &str) -> Result<Tz, MomentError> {
+        Tz::from_name(name)
+    }
+}
+
+/// The offset of a [`Tz`] at a particular instant, along with its abbreviation and whether
+/// daylight saving time is in effect.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct TzOffset {
+    tz: Tz,
+    utc_offset: FixedOffset,
+    is_dst: bool,
+    abbreviation: &'static str,
+}
+
+impl TzOffset {
+    pub fn tz(&self) -> Tz {
+        self.tz
+    }
+
+    /// The abbreviation in effect, such as `EST`, `BST` or `+0530` where the zone has none.
+    pub fn abbreviation(&self) -> &'static str {
+        self.abbreviation
+    }
+
+    pub fn is_dst(&self) -> bool {
+        self.is_dst
+    }
+}
+
+impl Offset for TzOffset {
+    fn fix(&self) -> FixedOffset {
+        self.utc_offset
+    }
+}
+
+impl fmt::Debug for TzOffset {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.abbreviation)
+    }
+}
+
+impl fmt::Display for TzOffset {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.abbreviation)
+    }
+}
+
+/// A change of offset, abbreviation or daylight saving time in a [`Tz`], such as clocks going
+/// forward in spring.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Transition {
+    instant: DateTime<Utc>,
+    old_offset: TzOffset,
+    new_offset: TzOffset,
+}
+
+impl Transition {
+    pub fn instant(&self) -> DateTime<Utc> {
+        self.instant
+    }
+
+    /// The offset in effect until the transition, with its abbreviation and daylight saving time
+    /// flag.
+    pub fn old_offset(&self) -> TzOffset {
+        self.old_offset
+    }
+
+    /// The offset in effect from the transition onwards.
+    pub fn new_offset(&self) -> TzOffset {
+        self.new_offset
+    }
+}
+
+impl TimeZone for Tz {
+    type Offset = TzOffset;
+
+    fn from_offset(offset: &TzOffset) -> Tz {
+        offset.tz
+    }
+
+    fn offset_from_local_date(&self, local: &NaiveDate) -> LocalResult<TzOffset> {
+        self.offset_from_local_datetime(&local.and_hms_opt(0, 0, 0).unwrap())
+    }
+
+    fn offset_from_local_datetime(&self, local: &NaiveDateTime) -> LocalResult<TzOffset> {
+        let data = self.data;
+        let local_seconds = local.and_utc().timestamp();
+        let span_offset =
+            |span: usize| i64::from(data.types[usize::from(data.spans[span])].utc_offset);
+        let footer = self.footer();
+        let mut offsets: Vec<TzOffset> =
+            candidate_spans(data.transitions, local_seconds, span_offset)
+                .filter(|span| footer.is_none() || *span < data.transitions.len())
+                .map(|span| self.offset_for_span(span))
+                .collect();
+        if let Some(footer) = footer {
+            offsets.extend(
+                footer
+                    .time_types_at_local(local_seconds)
+                    .into_iter()
+                    .filter(|time_type| {
+                        self.is_after_last_transition(
+                            local_seconds - i64::from(time_type.utc_offset),
+                        )
+                    })
+                    .map(|time_type| self.offset_for_time_type(time_type)),
+            );
+        }
+        local_result(offsets.into_iter())
+    }
+
+    fn offset_from_utc_date(&self, utc: &NaiveDate) -> TzOffset {
+        self.offset_from_utc_datetime(&utc.and_hms_opt(0, 0, 0).unwrap())
+    }
+
+    fn offset_from_utc_datetime(&self, utc: &NaiveDateTime) -> TzOffset {
+        let utc_seconds = utc.and_utc().timestamp();
+        match self.footer() {
+            Some(footer) if self.is_after_last_transition(utc_seconds) => {
+                self.offset_for_time_type(footer.time_type_at_utc(utc_seconds))
+            }
+            _ => self.offset_for_span(span_at_utc(self.data.transitions, utc_seconds)),
+        }
+    }
+}
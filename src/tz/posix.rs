@@ -0,0 +1,492 @@
+use super::{transitions::local_result, LocalTimeType, Tz, TzifZone};
+use crate::MomentError;
+use chrono::{
+    Datelike, FixedOffset, LocalResult, NaiveDate, NaiveDateTime, Offset, TimeZone, Weekday,
+};
+use std::{env, fmt, fs, str::FromStr, sync::Arc};
+
+const POSIX_TZ_FORMAT: &str = "std offset[dst[offset][,start[/time],end[/time]]]";
+
+const SECONDS_PER_HOUR: i64 = 3600;
+
+/// The day a daylight saving time rule starts or ends on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RuleDate {
+    /// `Jn`: the day of the year from 1 to 365, where February 29th is never counted.
+    Julian(u16),
+    /// `n`: the zero-based day of the year from 0 to 365, counting February 29th.
+    ZeroBased(u16),
+    /// `Mm.w.d`: weekday `d` (0 is Sunday) of week `w` (5 is the last) in month `m`.
+    MonthWeekDay { month: u32, week: u32, weekday: u32 },
+}
+
+impl RuleDate {
+    fn date(self, year: i32) -> Option<NaiveDate> {
+        match self {
+            RuleDate::Julian(day) => {
+                let is_leap_year = NaiveDate::from_ymd_opt(year, 2, 29).is_some();
+                let ordinal = if is_leap_year && day >= 60 {
+                    day + 1
+                } else {
+                    day
+                };
+                NaiveDate::from_yo_opt(year, u32::from(ordinal))
+            }
+            RuleDate::ZeroBased(day) => NaiveDate::from_yo_opt(year, 1)?
+                .checked_add_signed(chrono::Duration::days(i64::from(day))),
+            RuleDate::MonthWeekDay {
+                month,
+                week,
+                weekday,
+            } => {
+                let first = NaiveDate::from_ymd_opt(year, month, 1)?;
+                let first_weekday = first.weekday().num_days_from_sunday();
+                let day = 1 + (weekday + 7 - first_weekday) % 7 + (week - 1) * 7;
+                NaiveDate::from_ymd_opt(year, month, day)
+                    .or_else(|| NaiveDate::from_ymd_opt(year, month, day - 7))
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Rule {
+    date: RuleDate,
+    /// Seconds after local midnight, which may be negative or more than a day.
+    time: i64,
+}
+
+impl Rule {
+    /// The instant the rule takes effect in `year`, given the offset in effect before it.
+    fn utc_seconds(self, year: i32, utc_offset: i32) -> Option<i64> {
+        let midnight = self.date.date(year)?.and_hms_opt(0, 0, 0)?;
+        Some(midnight.and_utc().timestamp() + self.time - i64::from(utc_offset))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct DaylightSavingTime {
+    time_type: LocalTimeType,
+    start: Rule,
+    end: Rule,
+}
+
+/// A time zone described by a POSIX TZ string such as `EST5EDT,M3.2.0,M11.1.0` or
+/// `<+0530>-5:30`, as found in the `TZ` environment variable and at the end of TZif files.
+#[derive(Clone, PartialEq, Eq)]
+pub struct PosixTz {
+    tz_string: Arc<str>,
+    standard: LocalTimeType,
+    daylight_saving: Option<DaylightSavingTime>,
+}
+
+struct Parser<'a> {
+    input: &'a str,
+    position: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn error(&self) -> MomentError {
+        MomentError::Parse {
+            input: String::from(self.input),
+            format: Some(String::from(POSIX_TZ_FORMAT)),
+            offset: self.position,
+        }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.input.as_bytes().get(self.position).copied()
+    }
+
+    fn eat(&mut self, byte: u8) -> bool {
+        if self.peek() == Some(byte) {
+            self.position += 1;
+            return true;
+        }
+        false
+    }
+
+    fn expect(&mut self, byte: u8) -> Result<(), MomentError> {
+        if self.eat(byte) {
+            Ok(())
+        } else {
+            Err(self.error())
+        }
+    }
+
+    fn take_while<F: Fn(u8) -> bool>(&mut self, predicate: F) -> &'a str {
+        let start = self.position;
+        while self.peek().is_some_and(&predicate) {
+            self.position += 1;
+        }
+        &self.input[start..self.position]
+    }
+
+    /// An abbreviation is either at least three letters, or any letters, digits and signs
+    /// quoted in angle brackets, such as `<+0530>`.
+    fn abbreviation(&mut self) -> Result<&'a str, MomentError> {
+        let abbreviation = if self.eat(b'<') {
+            let abbreviation = self
+                .take_while(|byte| byte.is_ascii_alphanumeric() || byte == b'+' || byte == b'-');
+            self.expect(b'>')?;
+            abbreviation
+        } else {
+            self.take_while(|byte| byte.is_ascii_alphabetic())
+        };
+        if abbreviation.len() < 3 {
+            return Err(self.error());
+        }
+        Ok(abbreviation)
+    }
+
+    fn number(&mut self, max: i64) -> Result<i64, MomentError> {
+        let start = self.position;
+        let digits = self.take_while(|byte| byte.is_ascii_digit());
+        match digits.parse::<i64>() {
+            Ok(number) if number <= max => Ok(number),
+            _ => {
+                self.position = start;
+                Err(self.error())
+            }
+        }
+    }
+
+    /// `[+-]hh[:mm[:ss]]` in seconds. Hours go up to 167 so that rule times can use the
+    /// extension from RFC 8536.
+    fn time(&mut self, max_hours: i64) -> Result<i64, MomentError> {
+        let sign = if self.eat(b'-') {
+            -1
+        } else {
+            self.eat(b'+');
+            1
+        };
+        let mut seconds = self.number(max_hours)? * SECONDS_PER_HOUR;
+        if self.eat(b':') {
+            seconds += self.number(59)? * 60;
+            if self.eat(b':') {
+                seconds += self.number(59)?;
+            }
+        }
+        Ok(sign * seconds)
+    }
+
+    // POSIX offsets count hours west of Greenwich, the opposite of `utc_offset`.
+    fn utc_offset(&mut self) -> Result<i32, MomentError> {
+        Ok(-self.time(24)? as i32)
+    }
+
+    fn rule(&mut self) -> Result<Rule, MomentError> {
+        let date = if self.eat(b'J') {
+            let day = self.number(365)?;
+            if day == 0 {
+                return Err(self.error());
+            }
+            RuleDate::Julian(day as u16)
+        } else if self.eat(b'M') {
+            let month = self.number(12)?;
+            self.expect(b'.')?;
+            let week = self.number(5)?;
+            self.expect(b'.')?;
+            let weekday = self.number(6)?;
+            if month == 0 || week == 0 {
+                return Err(self.error());
+            }
+            RuleDate::MonthWeekDay {
+                month: month as u32,
+                week: week as u32,
+                weekday: weekday as u32,
+            }
+        } else {
+            RuleDate::ZeroBased(self.number(365)? as u16)
+        };
+        let time = if self.eat(b'/') {
+            self.time(167)?
+        } else {
+            2 * SECONDS_PER_HOUR
+        };
+        Ok(Rule { date, time })
+    }
+}
+
+impl PosixTz {
+    /// Parses the full POSIX TZ grammar, including the quoted abbreviations and extended rule
+    /// times from RFC 8536. When a daylight saving time name is given without rules, the US
+    /// rules (`M3.2.0,M11.1.0`) are used, as glibc does.
+    pub fn parse(input: &str) -> Result<PosixTz, MomentError> {
+        let mut parser = Parser { input, position: 0 };
+        let standard = LocalTimeType {
+            abbreviation: Arc::from(parser.abbreviation()?),
+            utc_offset: parser.utc_offset()?,
+            is_dst: false,
+        };
+
+        let mut daylight_saving = None;
+        if parser.peek().is_some() {
+            let abbreviation = parser.abbreviation()?;
+            let utc_offset = match parser.peek() {
+                Some(b',') | None => standard.utc_offset + SECONDS_PER_HOUR as i32,
+                _ => parser.utc_offset()?,
+            };
+            let (start, end) = if parser.eat(b',') {
+                let start = parser.rule()?;
+                parser.expect(b',')?;
+                (start, parser.rule()?)
+            } else {
+                let us_rule = |month, week| Rule {
+                    date: RuleDate::MonthWeekDay {
+                        month,
+                        week,
+                        weekday: Weekday::Sun.num_days_from_sunday(),
+                    },
+                    time: 2 * SECONDS_PER_HOUR,
+                };
+                (us_rule(3, 2), us_rule(11, 1))
+            };
+            daylight_saving = Some(DaylightSavingTime {
+                time_type: LocalTimeType {
+                    abbreviation: Arc::from(abbreviation),
+                    utc_offset,
+                    is_dst: true,
+                },
+                start,
+                end,
+            });
+        }
+
+        if parser.peek().is_some() {
+            return Err(parser.error());
+        }
+
+        // zic writes Europe/Dublin as `IST-1GMT0,M10.5.0,M3.5.0/1`, with daylight saving time
+        // behind standard time. Like Moment.js, the later of the two is read as DST.
+        let mut standard = standard;
+        if let Some(daylight_saving) = &mut daylight_saving {
+            if daylight_saving.time_type.utc_offset < standard.utc_offset {
+                daylight_saving.time_type.is_dst = false;
+                standard.is_dst = true;
+            }
+        }
+
+        Ok(PosixTz {
+            tz_string: Arc::from(input),
+            standard,
+            daylight_saving,
+        })
+    }
+
+    /// Reads the `TZ` environment variable, so that containers without zoneinfo files can still
+    /// use the right local time. A leading `:` is ignored, and an unset or empty `TZ` means UTC,
+    /// as it does for the C library. Besides POSIX TZ strings, zone names such as
+    /// `America/New_York` are looked up in the embedded tz database and absolute paths are read
+    /// as TZif files. Either way the zone's current rules are used, so earlier offsets may differ.
+    pub fn from_env() -> Result<PosixTz, MomentError> {
+        let tz_string = env::var("TZ").unwrap_or_default();
+        let tz_string = tz_string.strip_prefix(':').unwrap_or(&tz_string);
+        if tz_string.is_empty() {
+            return PosixTz::parse("UTC0");
+        }
+        if tz_string.starts_with('/') {
+            return PosixTz::from_tzif_file(tz_string);
+        }
+        PosixTz::parse(tz_string).or_else(|error| {
+            Tz::from_name(tz_string)
+                .ok()
+                .and_then(|tz| tz.footer())
+                .cloned()
+                .ok_or(error)
+        })
+    }
+
+    fn from_tzif_file(path: &str) -> Result<PosixTz, MomentError> {
+        let bytes = fs::read(path).map_err(|_| MomentError::UnknownTimeZone(String::from(path)))?;
+        TzifZone::from_bytes(path, &bytes)?
+            .footer()
+            .cloned()
+            .ok_or_else(|| MomentError::InvalidTimeZone {
+                name: String::from(path),
+                reason: "the file has no POSIX TZ footer",
+            })
+    }
+
+    /// The TZ string this zone was parsed from.
+    pub fn tz_string(&self) -> &str {
+        &self.tz_string
+    }
+
+    fn offset(&self, local_time_type: &LocalTimeType) -> PosixOffset {
+        PosixOffset {
+            tz: self.clone(),
+            local_time_type: local_time_type.clone(),
+        }
+    }
+
+    /// The standard time type, then the daylight saving time one if there is one.
+    pub(super) fn time_types(&self) -> impl Iterator<Item = &LocalTimeType> {
+        std::iter::once(&self.standard).chain(
+            self.daylight_saving
+                .iter()
+                .map(|daylight_saving| &daylight_saving.time_type),
+        )
+    }
+
+    /// The local time type in effect at `utc_seconds`.
+    pub(crate) fn time_type_at_utc(&self, utc_seconds: i64) -> &LocalTimeType {
+        let daylight_saving = match &self.daylight_saving {
+            Some(daylight_saving) => daylight_saving,
+            None => return &self.standard,
+        };
+
+        // The rules are evaluated in the standard time year, which is the year they apply to
+        // everywhere except within hours of new year.
+        let local_seconds = utc_seconds + i64::from(self.standard.utc_offset);
+        let year = match chrono::DateTime::from_timestamp(local_seconds, 0) {
+            Some(local) => local.year(),
+            None => return &self.standard,
+        };
+        let start = daylight_saving
+            .start
+            .utc_seconds(year, self.standard.utc_offset);
+        let end = daylight_saving
+            .end
+            .utc_seconds(year, daylight_saving.time_type.utc_offset);
+        let is_dst = match (start, end) {
+            (Some(start), Some(end)) if start <= end => start <= utc_seconds && utc_seconds < end,
+            // Southern hemisphere zones are on daylight saving time across new year.
+            (Some(start), Some(end)) => utc_seconds < end || start <= utc_seconds,
+            _ => false,
+        };
+
+        if is_dst {
+            &daylight_saving.time_type
+        } else {
+            &self.standard
+        }
+    }
+
+    /// The instants after `utc_seconds` at which the rules start or end daylight saving time,
+    /// oldest first. There are none if the zone has no daylight saving time.
+    pub(super) fn transitions_after(&self, utc_seconds: i64) -> impl Iterator<Item = i64> + '_ {
+        let year =
+            chrono::DateTime::from_timestamp(utc_seconds, 0).map_or(i32::MAX, |utc| utc.year());
+        self.daylight_saving
+            .iter()
+            .flat_map(move |daylight_saving| {
+                // The year before is included because a rule time past midnight can move its
+                // transition into the next year.
+                (year.saturating_sub(1)..)
+                    .map_while(move |year| {
+                        let start = daylight_saving
+                            .start
+                            .utc_seconds(year, self.standard.utc_offset)?;
+                        let end = daylight_saving
+                            .end
+                            .utc_seconds(year, daylight_saving.time_type.utc_offset)?;
+                        Some([start.min(end), start.max(end)])
+                    })
+                    .flatten()
+            })
+            .filter(move |transition| *transition > utc_seconds)
+    }
+
+    /// The local time types in which the wall clock shows `local_seconds`, earliest instant
+    /// first: one normally, two when the clock is turned back and none when it skips ahead.
+    pub(crate) fn time_types_at_local(&self, local_seconds: i64) -> Vec<&LocalTimeType> {
+        let mut time_types: Vec<&LocalTimeType> = self
+            .daylight_saving
+            .iter()
+            .map(|daylight_saving| &daylight_saving.time_type)
+            .chain(std::iter::once(&self.standard))
+            .filter(|time_type| {
+                let utc_seconds = local_seconds - i64::from(time_type.utc_offset);
+                self.time_type_at_utc(utc_seconds) == *time_type
+            })
+            .collect();
+        time_types.sort_by_key(|time_type| -i64::from(time_type.utc_offset));
+        time_types.dedup();
+        time_types
+    }
+}
+
+impl FromStr for PosixTz {
+    type Err = MomentError;
+
+    fn from_str(input: &str) -> Result<PosixTz, MomentError> {
+        PosixTz::parse(input)
+    }
+}
+
+impl fmt::Debug for PosixTz {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.tz_string)
+    }
+}
+
+impl fmt::Display for PosixTz {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.tz_string)
+    }
+}
+
+/// The offset of a [`PosixTz`] at a particular instant, along with its abbreviation and whether
+/// daylight saving time is in effect.
+#[derive(Clone, PartialEq, Eq)]
+pub struct PosixOffset {
+    tz: PosixTz,
+    local_time_type: LocalTimeType,
+}
+
+impl PosixOffset {
+    pub fn abbreviation(&self) -> &str {
+        &self.local_time_type.abbreviation
+    }
+
+    pub fn is_dst(&self) -> bool {
+        self.local_time_type.is_dst
+    }
+}
+
+impl Offset for PosixOffset {
+    fn fix(&self) -> FixedOffset {
+        FixedOffset::east_opt(self.local_time_type.utc_offset).unwrap()
+    }
+}
+
+impl fmt::Debug for PosixOffset {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.abbreviation())
+    }
+}
+
+impl fmt::Display for PosixOffset {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.abbreviation())
+    }
+}
+
+impl TimeZone for PosixTz {
+    type Offset = PosixOffset;
+
+    fn from_offset(offset: &PosixOffset) -> PosixTz {
+        offset.tz.clone()
+    }
+
+    fn offset_from_local_date(&self, local: &NaiveDate) -> LocalResult<PosixOffset> {
+        self.offset_from_local_datetime(&local.and_hms_opt(0, 0, 0).unwrap())
+    }
+
+    fn offset_from_local_datetime(&self, local: &NaiveDateTime) -> LocalResult<PosixOffset> {
+        local_result(
+            self.time_types_at_local(local.and_utc().timestamp())
+                .into_iter(),
+        )
+        .map(|local_time_type| self.offset(local_time_type))
+    }
+
+    fn offset_from_utc_date(&self, utc: &NaiveDate) -> PosixOffset {
+        self.offset_from_utc_datetime(&utc.and_hms_opt(0, 0, 0).unwrap())
+    }
+
+    fn offset_from_utc_datetime(&self, utc: &NaiveDateTime) -> PosixOffset {
+        self.offset(self.time_type_at_utc(utc.and_utc().timestamp()))
+    }
+}
@@ -0,0 +1,36 @@
+use chrono::LocalResult;
+
+const SECONDS_PER_DAY: i64 = 86_400;
+
+/// The index of the span in effect at `utc_seconds`. Span `0` is in effect before the first
+/// transition and span `n` from the `n`th transition onwards.
+pub(crate) fn span_at_utc(transitions: &[i64], utc_seconds: i64) -> usize {
+    transitions.partition_point(|transition| *transition <= utc_seconds)
+}
+
+/// The spans in which the wall clock shows `local_seconds`, in order. `utc_offset` gives the
+/// offset of each span.
+pub(crate) fn candidate_spans<'a, F: Fn(usize) -> i64 + 'a>(
+    transitions: &'a [i64],
+    local_seconds: i64,
+    utc_offset: F,
+) -> impl Iterator<Item = usize> + 'a {
+    // No offset is larger than a day, so only spans within a day of the local time can match.
+    let first = span_at_utc(transitions, local_seconds - SECONDS_PER_DAY);
+    let last = span_at_utc(transitions, local_seconds + SECONDS_PER_DAY);
+    (first..=last).filter(move |span| {
+        let utc_seconds = local_seconds - utc_offset(*span);
+        let starts_before = *span == 0 || transitions[*span - 1] <= utc_seconds;
+        let ends_after = *span == transitions.len() || utc_seconds < transitions[*span];
+        starts_before && ends_after
+    })
+}
+
+/// One match normally, two when the clock is turned back and none when it skips ahead.
+pub(crate) fn local_result<T, I: Iterator<Item = T>>(mut matches: I) -> LocalResult<T> {
+    match (matches.next(), matches.next()) {
+        (Some(earlier), Some(later)) => LocalResult::Ambiguous(earlier, later),
+        (Some(single), None) => LocalResult::Single(single),
+        _ => LocalResult::None,
+    }
+}
@@ -0,0 +1,468 @@
+use super::{
+    posix::PosixTz,
+    transitions::{candidate_spans, local_result, span_at_utc},
+    LocalTimeType,
+};
+use crate::MomentError;
+use chrono::{FixedOffset, LocalResult, NaiveDate, NaiveDateTime, Offset, TimeZone};
+use std::{
+    convert::TryFrom,
+    fmt, fs, io,
+    path::{Component, Path},
+    sync::Arc,
+};
+
+/// Where zoneinfo files are installed on most Unix systems.
+pub const DEFAULT_ZONEINFO_DIRECTORY: &str = "/usr/share/zoneinfo";
+
+#[derive(Debug, PartialEq, Eq)]
+struct TzifData {
+    name: String,
+    transitions: Vec<i64>,
+    spans: Vec<usize>,
+    types: Vec<LocalTimeType>,
+    footer: Option<PosixTz>,
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, length: usize) -> Option<&'a [u8]> {
+        let end = self.position.checked_add(length)?;
+        let bytes = self.bytes.get(self.position..end)?;
+        self.position = end;
+        Some(bytes)
+    }
+
+    fn remaining(&self) -> usize {
+        self.bytes.len() - self.position
+    }
+
+    fn u8(&mut self) -> Option<u8> {
+        Some(self.take(1)?[0])
+    }
+
+    fn u32(&mut self) -> Option<u32> {
+        Some(u32::from_be_bytes(<[u8; 4]>::try_from(self.take(4)?).ok()?))
+    }
+
+    fn i32(&mut self) -> Option<i32> {
+        Some(i32::from_be_bytes(<[u8; 4]>::try_from(self.take(4)?).ok()?))
+    }
+
+    fn i64(&mut self) -> Option<i64> {
+        Some(i64::from_be_bytes(<[u8; 8]>::try_from(self.take(8)?).ok()?))
+    }
+}
+
+struct Header {
+    version: u8,
+    is_ut_count: usize,
+    is_standard_count: usize,
+    leap_count: usize,
+    time_count: usize,
+    type_count: usize,
+    char_count: usize,
+}
+
+impl Header {
+    fn read(reader: &mut Reader) -> Result<Header, &'static str> {
+        if reader.take(4) != Some(b"TZif") {
+            return Err("the file is not a TZif file");
+        }
+        let truncated = "the header is truncated";
+        let version = reader.u8().ok_or(truncated)?;
+        reader.take(15).ok_or(truncated)?;
+        let mut count = || reader.u32().map(|count| count as usize).ok_or(truncated);
+        let header = Header {
+            version,
+            is_ut_count: count()?,
+            is_standard_count: count()?,
+            leap_count: count()?,
+            time_count: count()?,
+            type_count: count()?,
+            char_count: count()?,
+        };
+        if header.type_count == 0
+            || header.char_count == 0
+            || (header.is_ut_count != 0 && header.is_ut_count != header.type_count)
+            || (header.is_standard_count != 0 && header.is_standard_count != header.type_count)
+        {
+            return Err("the header counts are inconsistent");
+        }
+        Ok(header)
+    }
+
+    // `None` if the counts are too large to add up, which no file that fits in memory has.
+    fn data_length(&self, time_size: usize) -> Option<usize> {
+        let lengths = [
+            self.time_count.checked_mul(time_size + 1)?,
+            self.type_count.checked_mul(6)?,
+            self.char_count,
+            self.leap_count.checked_mul(time_size + 4)?,
+            self.is_standard_count,
+            self.is_ut_count,
+        ];
+        lengths
+            .iter()
+            .try_fold(0_usize, |total, length| total.checked_add(*length))
+    }
+}
+
+type TzifBlock = (Vec<i64>, Vec<usize>, Vec<LocalTimeType>);
+
+fn read_block(
+    reader: &mut Reader,
+    header: &Header,
+    time_size: usize,
+) -> Result<TzifBlock, &'static str> {
+    let truncated = "the data block is truncated";
+    // The counts come from the file, so they are checked against its size before allocating.
+    if header
+        .data_length(time_size)
+        .is_none_or(|length| length > reader.remaining())
+    {
+        return Err(truncated);
+    }
+    let mut transitions = Vec::with_capacity(header.time_count);
+    for _ in 0..header.time_count {
+        let transition = if time_size == 4 {
+            reader.i32().map(i64::from)
+        } else {
+            reader.i64()
+        };
+        transitions.push(transition.ok_or(truncated)?);
+    }
+    if transitions.windows(2).any(|pair| pair[0] >= pair[1]) {
+        return Err("the transitions are not in order");
+    }
+
+    // Time type 0 applies before the first transition.
+    let mut spans = vec![0];
+    for _ in 0..header.time_count {
+        let index = usize::from(reader.u8().ok_or(truncated)?);
+        if index >= header.type_count {
+            return Err("a transition refers to a missing time type");
+        }
+        spans.push(index);
+    }
+
+    let mut type_records = Vec::with_capacity(header.type_count);
+    for _ in 0..header.type_count {
+        let utc_offset = reader.i32().ok_or(truncated)?;
+        let is_dst = reader.u8().ok_or(truncated)?;
+        let abbreviation_index = usize::from(reader.u8().ok_or(truncated)?);
+        if FixedOffset::east_opt(utc_offset).is_none() || is_dst > 1 {
+            return Err("a time type is out of range");
+        }
+        type_records.push((utc_offset, is_dst == 1, abbreviation_index));
+    }
+
+    let abbreviations = reader.take(header.char_count).ok_or(truncated)?;
+    let mut types = Vec::with_capacity(header.type_count);
+    for (utc_offset, is_dst, abbreviation_index) in type_records {
+        let abbreviation = abbreviations
+            .get(abbreviation_index..)
+            .and_then(|bytes| bytes.split(|byte| *byte == 0).next())
+            .and_then(|bytes| std::str::from_utf8(bytes).ok())
+            .ok_or("an abbreviation is out of range")?;
+        types.push(LocalTimeType {
+            utc_offset,
+            is_dst,
+            abbreviation: Arc::from(abbreviation),
+        });
+    }
+
+    // Leap seconds and the standard/wall and UT/local indicators only matter to zic.
+    reader
+        .take(header.leap_count * (time_size + 4) + header.is_standard_count + header.is_ut_count)
+        .ok_or(truncated)?;
+
+    Ok((transitions, spans, types))
+}
+
+// zic gives Europe/Dublin, Africa/Windhoek and Morocco negative daylight saving time, so that
+// winter time is the one flagged as DST. As in the embedded zones, a DST span that is behind the
+// standard time on both sides is read as standard time, and a span between two of those as DST.
+// The last span has no later neighbour, so the footer, which `PosixTz::parse` already corrects,
+// decides for it.
+fn normalize_negative_dst(
+    spans: &mut [usize],
+    types: &mut Vec<LocalTimeType>,
+    footer: Option<&PosixTz>,
+) {
+    let time_type = |span: usize| &types[spans[span]];
+    let is_dst_in_footer = |span: usize| {
+        let time_type = time_type(span);
+        footer?
+            .time_types()
+            .find(|footer_type| {
+                footer_type.utc_offset == time_type.utc_offset
+                    && footer_type.abbreviation == time_type.abbreviation
+            })
+            .map(|footer_type| footer_type.is_dst)
+    };
+    let is_negative = |span: usize| {
+        let is_ahead = |neighbour: usize| {
+            !time_type(neighbour).is_dst
+                && time_type(neighbour).utc_offset > time_type(span).utc_offset
+        };
+        let is_ahead_after = if span + 1 < spans.len() {
+            is_ahead(span + 1)
+        } else {
+            is_dst_in_footer(span) == Some(false)
+        };
+        time_type(span).is_dst && span > 0 && is_ahead(span - 1) && is_ahead_after
+    };
+    let negative: Vec<bool> = (0..spans.len()).map(is_negative).collect();
+    let normalized: Vec<(usize, LocalTimeType)> = (0..spans.len())
+        .filter_map(|span| {
+            let is_dst = if negative[span] {
+                false
+            } else if !time_type(span).is_dst {
+                let is_negative_after = match negative.get(span + 1) {
+                    Some(is_negative) => *is_negative,
+                    None => is_dst_in_footer(span) == Some(true),
+                };
+                span > 0 && negative[span - 1] && is_negative_after
+            } else {
+                return None;
+            };
+            Some((
+                span,
+                LocalTimeType {
+                    is_dst,
+                    ..time_type(span).clone()
+                },
+            ))
+        })
+        .collect();
+    for (span, time_type) in normalized {
+        spans[span] = match types.iter().position(|existing| *existing == time_type) {
+            Some(index) => index,
+            None => {
+                types.push(time_type);
+                types.len() - 1
+            }
+        };
+    }
+}
+
+fn read_tzif(bytes: &[u8]) -> Result<(TzifBlock, Option<PosixTz>), &'static str> {
+    let mut reader = Reader { bytes, position: 0 };
+    let header = Header::read(&mut reader)?;
+    if header.version == 0 {
+        return Ok((read_block(&mut reader, &header, 4)?, None));
+    }
+
+    // Version 2 and later files repeat the data with 64-bit times, followed by a footer.
+    header
+        .data_length(4)
+        .and_then(|length| reader.take(length))
+        .ok_or("the data block is truncated")?;
+    let header = Header::read(&mut reader)?;
+    let block = read_block(&mut reader, &header, 8)?;
+
+    let footer = &bytes[reader.position..];
+    let footer = footer
+        .strip_prefix(b"\n")
+        .and_then(|footer| footer.split(|byte| *byte == b'\n').next())
+        .and_then(|footer| std::str::from_utf8(footer).ok())
+        .ok_or("the footer is malformed")?;
+    if footer.is_empty() {
+        return Ok((block, None));
+    }
+    let footer = PosixTz::parse(footer).map_err(|_| "the footer is not a valid TZ string")?;
+    Ok((block, Some(footer)))
+}
+
+/// A time zone read from a binary TZif (versions 1 to 4) file, such as those installed in
+/// `/usr/share/zoneinfo`, so that tz database updates don't require a rebuild. Instants after
+/// the last transition in the file follow its POSIX TZ footer. Leap seconds are ignored.
+#[derive(Clone, PartialEq, Eq)]
+pub struct TzifZone(Arc<TzifData>);
+
+impl TzifZone {
+    /// Reads the named zone from [`DEFAULT_ZONEINFO_DIRECTORY`].
+    pub fn from_name(name: &str) -> Result<TzifZone, MomentError> {
+        TzifZone::from_directory(DEFAULT_ZONEINFO_DIRECTORY, name)
+    }
+
+    /// Reads the named zone, such as `America/New_York`, from a zoneinfo directory.
+    pub fn from_directory<P: AsRef<Path>>(
+        directory: P,
+        name: &str,
+    ) -> Result<TzifZone, MomentError> {
+        let relative_path = Path::new(name);
+        let is_relative = relative_path
+            .components()
+            .all(|component| matches!(component, Component::Normal(_)));
+        if name.is_empty() || !is_relative {
+            return Err(MomentError::UnknownTimeZone(String::from(name)));
+        }
+
+        match fs::read(directory.as_ref().join(relative_path)) {
+            Ok(bytes) => TzifZone::from_bytes(name, &bytes),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => {
+                Err(MomentError::UnknownTimeZone(String::from(name)))
+            }
+            Err(_) => Err(MomentError::InvalidTimeZone {
+                name: String::from(name),
+                reason: "the file could not be read",
+            }),
+        }
+    }
+
+    /// Reads a zone from the contents of a TZif file.
+    pub fn from_bytes(name: &str, bytes: &[u8]) -> Result<TzifZone, MomentError> {
+        let ((transitions, mut spans, mut types), footer) =
+            read_tzif(bytes).map_err(|reason| MomentError::InvalidTimeZone {
+                name: String::from(name),
+                reason,
+            })?;
+        normalize_negative_dst(&mut spans, &mut types, footer.as_ref());
+        Ok(TzifZone(Arc::new(TzifData {
+            name: String::from(name),
+            transitions,
+            spans,
+            types,
+            footer,
+        })))
+    }
+
+    pub fn name(&self) -> &str {
+        &self.0.name
+    }
+
+    /// The POSIX TZ footer followed after the last transition in the file.
+    pub(super) fn footer(&self) -> Option<&PosixTz> {
+        self.0.footer.as_ref()
+    }
+
+    fn is_after_last_transition(&self, utc_seconds: i64) -> bool {
+        self.0
+            .transitions
+            .last()
+            .is_none_or(|last| utc_seconds >= *last)
+    }
+
+    fn time_type_at_utc(&self, utc_seconds: i64) -> &LocalTimeType {
+        let data = &self.0;
+        match &data.footer {
+            Some(footer) if self.is_after_last_transition(utc_seconds) => {
+                footer.time_type_at_utc(utc_seconds)
+            }
+            _ => &data.types[data.spans[span_at_utc(&data.transitions, utc_seconds)]],
+        }
+    }
+
+    fn time_types_at_local(&self, local_seconds: i64) -> LocalResult<&LocalTimeType> {
+        let data = &self.0;
+        let span_offset = |span: usize| i64::from(data.types[data.spans[span]].utc_offset);
+        let mut time_types: Vec<&LocalTimeType> =
+            candidate_spans(&data.transitions, local_seconds, span_offset)
+                .filter(|span| data.footer.is_none() || *span < data.transitions.len())
+                .map(|span| &data.types[data.spans[span]])
+                .collect();
+        if let Some(footer) = &data.footer {
+            time_types.extend(
+                footer
+                    .time_types_at_local(local_seconds)
+                    .into_iter()
+                    .filter(|time_type| {
+                        self.is_after_last_transition(
+                            local_seconds - i64::from(time_type.utc_offset),
+                        )
+                    }),
+            );
+        }
+        local_result(time_types.into_iter())
+    }
+
+    fn offset(&self, local_time_type: &LocalTimeType) -> TzifOffset {
+        TzifOffset {
+            zone: self.clone(),
+            local_time_type: local_time_type.clone(),
+        }
+    }
+}
+
+impl fmt::Debug for TzifZone {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0.name)
+    }
+}
+
+impl fmt::Display for TzifZone {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0.name)
+    }
+}
+
+/// The offset of a [`TzifZone`] at a particular instant, along with its abbreviation and whether
+/// daylight saving time is in effect.
+#[derive(Clone, PartialEq, Eq)]
+pub struct TzifOffset {
+    zone: TzifZone,
+    local_time_type: LocalTimeType,
+}
+
+impl TzifOffset {
+    pub fn zone(&self) -> &TzifZone {
+        &self.zone
+    }
+
+    pub fn abbreviation(&self) -> &str {
+        &self.local_time_type.abbreviation
+    }
+
+    pub fn is_dst(&self) -> bool {
+        self.local_time_type.is_dst
+    }
+}
+
+impl Offset for TzifOffset {
+    fn fix(&self) -> FixedOffset {
+        FixedOffset::east_opt(self.local_time_type.utc_offset).unwrap()
+    }
+}
+
+impl fmt::Debug for TzifOffset {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.abbreviation())
+    }
+}
+
+impl fmt::Display for TzifOffset {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.abbreviation())
+    }
+}
+
+impl TimeZone for TzifZone {
+    type Offset = TzifOffset;
+
+    fn from_offset(offset: &TzifOffset) -> TzifZone {
+        offset.zone.clone()
+    }
+
+    fn offset_from_local_date(&self, local: &NaiveDate) -> LocalResult<TzifOffset> {
+        self.offset_from_local_datetime(&local.and_hms_opt(0, 0, 0).unwrap())
+    }
+
+    fn offset_from_local_datetime(&self, local: &NaiveDateTime) -> LocalResult<TzifOffset> {
+        self.time_types_at_local(local.and_utc().timestamp())
+            .map(|local_time_type| self.offset(local_time_type))
+    }
+
+    fn offset_from_utc_date(&self, utc: &NaiveDate) -> TzifOffset {
+        self.offset_from_utc_datetime(&utc.and_hms_opt(0, 0, 0).unwrap())
+    }
+
+    fn offset_from_utc_datetime(&self, utc: &NaiveDateTime) -> TzifOffset {
+        self.offset(self.time_type_at_utc(utc.and_utc().timestamp()))
+    }
+}
@@ -0,0 +1,45 @@
+use super::Tz;
+use crate::MomentError;
+
+// Generated by `build.rs` from CLDR's `windowsZones.xml` in `cldr/`. Each entry maps a Windows
+// time zone ID and a territory to IANA zones. Territory `001` gives the zone for the whole
+// Windows zone and `ZZ` the `Etc/` zones with the same offset.
+include!(concat!(env!("OUT_DIR"), "/windows_zones.rs"));
+
+const WORLD_TERRITORY: &str = "001";
+
+impl Tz {
+    /// Looks up the zone for a Windows time zone ID, such as `Pacific Standard Time`, using the
+    /// CLDR `windowsZones` mapping. With a territory such as `CA`, the zone used in that territory
+    /// is returned (`America/Vancouver`). Without one, or when the territory has no zone of its
+    /// own, the main zone for the Windows ID is returned (`America/Los_Angeles`).
+    pub fn from_windows_name(name: &str, territory: Option<&str>) -> Result<Tz, MomentError> {
+        let find = |territory: &str| {
+            WINDOWS_ZONES
+                .iter()
+                .find(|(windows_name, zone_territory, _)| {
+                    windows_name.eq_ignore_ascii_case(name)
+                        && zone_territory.eq_ignore_ascii_case(territory)
+                })
+                .map(|(_, _, zones)| zones[0])
+        };
+        territory
+            .and_then(find)
+            .or_else(|| find(WORLD_TERRITORY))
+            .ok_or_else(|| MomentError::UnknownTimeZone(String::from(name)))
+            .and_then(Tz::from_name)
+    }
+
+    /// The Windows time zone ID for this zone, such as `Pacific Standard Time` for
+    /// `America/Los_Angeles` or `US/Pacific`, or `None` if Windows has no equivalent.
+    pub fn windows_name(&self) -> Option<&'static str> {
+        WINDOWS_ZONES
+            .iter()
+            .find(|(_, _, zones)| {
+                zones
+                    .iter()
+                    .any(|zone| Tz::from_name(zone).is_ok_and(|zone| zone.is_same_zone(self)))
+            })
+            .map(|(windows_name, _, _)| *windows_name)
+    }
+}
@@ -1,4 +1,4 @@
-#[derive(Debug, Clone, Eq, PartialEq, PartialOrd, Hash)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, PartialOrd, Hash)]
 pub enum UnitOfTime {
     Nanosecond,
     Microsecond,
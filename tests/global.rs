@@ -0,0 +1,159 @@
+// Tests that change process-wide state, such as the default locale or environment variables.
+// They run in their own test binary so that the tests in `test.rs` never see the changes, and
+// take `GLOBAL_STATE` so that they don't see each other's.
+use chrono::prelude::*;
+use lazy_static::lazy_static;
+use moment::{
+    locale::{
+        default_locale, find_locale, locale_names, negotiate_env_locale, register_locale,
+        set_default_locale, Locale,
+    },
+    locales::LOCALE_EN_US,
+    tz::PosixTz,
+    Moment, MomentError,
+};
+use std::{
+    env,
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+};
+
+lazy_static! {
+    static ref GLOBAL_STATE: Mutex<()> = Mutex::new(());
+}
+
+// A failed assertion poisons the lock, but the guards below have already restored the state.
+fn lock_global_state() -> MutexGuard<'static, ()> {
+    GLOBAL_STATE.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+// Restores the default locale when dropped, even if an assertion fails.
+struct DefaultLocaleGuard(Arc<Locale>);
+
+impl DefaultLocaleGuard {
+    fn set<L: Into<Arc<Locale>>>(locale: L) -> DefaultLocaleGuard {
+        let previous = default_locale();
+        set_default_locale(locale);
+        DefaultLocaleGuard(previous)
+    }
+}
+
+impl Drop for DefaultLocaleGuard {
+    fn drop(&mut self) {
+        set_default_locale(Arc::clone(&self.0));
+    }
+}
+
+// Restores environment variables when dropped, even if an assertion fails.
+struct EnvGuard(Vec<(&'static str, Option<String>)>);
+
+impl EnvGuard {
+    fn save(variables: &[&'static str]) -> EnvGuard {
+        EnvGuard(
+            variables
+                .iter()
+                .map(|variable| (*variable, env::var(variable).ok()))
+                .collect(),
+        )
+    }
+}
+
+impl Drop for EnvGuard {
+    fn drop(&mut self) {
+        for (variable, value) in self.0.iter() {
+            match value {
+                Some(value) => env::set_var(variable, value),
+                None => env::remove_var(variable),
+            }
+        }
+    }
+}
+
+#[test]
+fn test_default_locale() {
+    let _lock = lock_global_state();
+    assert!(register_locale(Locale {
+        name: "en-x-test".into(),
+        ..LOCALE_EN_US.clone()
+    })
+    .is_none());
+    assert!(locale_names().contains(&String::from("en-x-test")));
+
+    let moment: Moment<Utc> = {
+        let _default = DefaultLocaleGuard::set(find_locale("en-x-test").unwrap());
+        let moment: Moment<Utc> = Utc.with_ymd_and_hms(2019, 5, 20, 15, 4, 5).unwrap().into();
+        assert_eq!(moment.locale_name(), "en-x-test");
+        assert_eq!(
+            Moment::<FixedOffset>::parse("2019-05-20", "YYYY-MM-DD")
+                .unwrap()
+                .locale_name(),
+            "en-x-test"
+        );
+        moment
+    };
+    assert_eq!(moment.locale_data().name, "en-x-test");
+    assert_eq!(Moment::<Local>::new().unwrap().locale_name(), "en");
+}
+
+#[test]
+fn test_env_locale() {
+    let _lock = lock_global_state();
+    let _env = EnvGuard::save(&["LC_ALL", "LC_TIME", "LANG"]);
+    env::remove_var("LC_ALL");
+    env::set_var("LC_TIME", "en_GB.UTF-8@euro");
+    env::set_var("LANG", "xx_XX.UTF-8");
+    assert_eq!(negotiate_env_locale().unwrap().locale.name, "en-gb");
+    env::remove_var("LC_TIME");
+    assert!(negotiate_env_locale().is_none());
+    env::set_var("LC_ALL", "C.UTF-8");
+    assert!(negotiate_env_locale().is_none());
+}
+
+#[test]
+fn test_env_time_zone() {
+    let _lock = lock_global_state();
+    let _env = EnvGuard::save(&["TZ"]);
+    let july = |tz: &PosixTz| tz.with_ymd_and_hms(2019, 7, 15, 12, 0, 0).unwrap();
+
+    env::remove_var("TZ");
+    assert_eq!(PosixTz::from_env().unwrap().tz_string(), "UTC0");
+    env::set_var("TZ", "EST5EDT,M3.2.0,M11.1.0");
+    assert_eq!(
+        july(&PosixTz::from_env().unwrap()).offset().abbreviation(),
+        "EDT"
+    );
+
+    // Zone names use the current rules of the embedded zone.
+    for tz_string in ["America/New_York", ":America/New_York", "US/Eastern"].iter() {
+        env::set_var("TZ", tz_string);
+        let tz = PosixTz::from_env().unwrap();
+        assert_eq!(tz.tz_string(), "EST5EDT,M3.2.0,M11.1.0");
+        assert_eq!(
+            july(&tz).to_rfc3339(),
+            String::from("2019-07-15T12:00:00-04:00")
+        );
+    }
+    env::set_var("TZ", "UTC");
+    assert_eq!(PosixTz::from_env().unwrap().tz_string(), "UTC0");
+
+    // Paths are read as TZif files and their footer is used.
+    env::set_var(
+        "TZ",
+        concat!(
+            ":",
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/zoneinfo/Europe/Dublin"
+        ),
+    );
+    let dublin = PosixTz::from_env().unwrap();
+    assert_eq!(dublin.tz_string(), "IST-1GMT0,M10.5.0,M3.5.0/1");
+    assert_eq!(july(&dublin).offset().abbreviation(), "IST");
+    assert!(july(&dublin).offset().is_dst());
+
+    env::set_var("TZ", "/nonexistent/zoneinfo/Mars");
+    assert_eq!(
+        PosixTz::from_env().unwrap_err(),
+        MomentError::UnknownTimeZone(String::from("/nonexistent/zoneinfo/Mars"))
+    );
+    env::set_var("TZ", "Mars/Olympus_Mons");
+    assert!(PosixTz::from_env().is_err());
+}
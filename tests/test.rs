@@ -1,5 +1,20 @@
 use chrono::prelude::*;
-use moment::{duration, Moment, UnitOfTime};
+use chrono::Duration;
+use moment::{
+    checked_duration, duration,
+    locale::{
+        find_locale, negotiate_accept_language, negotiate_locale, plural_category,
+        relative_time_config, set_relative_time_config, LanguageTag, Locale, ParseMode,
+        PluralCategory, PluralForms, RelativeTimeConfig, RelativeTimeFormat,
+        RelativeTimeThresholds,
+    },
+    locales::{LOCALE_EN_GB, LOCALE_EN_US},
+    tz::{
+        metazone, set_time_zone_abbreviations, time_zone_abbreviations, Disambiguation, PosixTz,
+        TimeZoneAbbreviations, Tz, TzifZone,
+    },
+    Moment, MomentDuration, MomentError, UnitOfTime, UtcOffset,
+};
 use std::collections::HashSet;
 
 // TODO: Make test that compares output to moment.js
@@ -20,3 +35,1973 @@ fn test() {
     // assert_eq!(cal, String::from("Monday at 12:48 AM"));
     println!("{}", cal);
 }
+
+#[test]
+fn test_parse() {
+    let moment = Moment::<FixedOffset>::parse(
+        "2019-05-20 15:04:05.123 +05:30",
+        "YYYY-MM-DD HH:mm:ss.SSS Z",
+    )
+    .unwrap();
+    assert_eq!(
+        moment.to_rfc3339(),
+        String::from("2019-05-20T15:04:05.123+05:30")
+    );
+
+    let format = "[Week of] YYYY-MM-DDTHH:mm:ss.SSSSSS ZZ";
+    let formatted = moment.format(String::from(format));
+    assert_eq!(
+        formatted,
+        String::from("Week of 2019-05-20T15:04:05.123000 +0530")
+    );
+    let reparsed = Moment::<FixedOffset>::parse(formatted.as_str(), format).unwrap();
+    assert_eq!(*reparsed, *moment);
+
+    let timestamp = Moment::<FixedOffset>::parse("1558364645123", "x").unwrap();
+    assert_eq!(timestamp.timestamp_millis(), 1_558_364_645_123);
+
+    let en_gb =
+        Moment::<FixedOffset>::parse_with_locale("20/05/2019", "L", LOCALE_EN_GB.clone()).unwrap();
+    assert_eq!(
+        en_gb.format(String::from("YYYY-MM-DD")),
+        String::from("2019-05-20")
+    );
+
+    assert!(Moment::<FixedOffset>::parse("2019-13-20", "YYYY-MM-DD").is_err());
+    assert!(Moment::<FixedOffset>::parse("nothing", "YYYY-MM-DD").is_err());
+}
+
+#[test]
+fn test_parse_locale_strings() {
+    let moment =
+        Moment::<FixedOffset>::parse("May 20th 2019 3:00 PM", "MMMM Do YYYY h:mm A").unwrap();
+    assert_eq!(
+        moment.to_rfc3339(),
+        String::from("2019-05-20T15:00:00+00:00")
+    );
+
+    let lowercase = Moment::<FixedOffset>::parse("monday, may 20, 2019 12:30 am", "LLLL").unwrap();
+    assert_eq!(
+        lowercase.to_rfc3339(),
+        String::from("2019-05-20T00:30:00+00:00")
+    );
+
+    for (locale, format) in [
+        (LOCALE_EN_US.clone(), "LLLL"),
+        (LOCALE_EN_GB.clone(), "LLLL"),
+    ]
+    .iter()
+    {
+        let formatted = moment
+            .clone()
+            .locale(locale.clone())
+            .format(String::from(*format));
+        let reparsed =
+            Moment::<FixedOffset>::parse_with_locale(formatted.as_str(), format, locale.clone())
+                .unwrap();
+        assert_eq!(*reparsed, *moment);
+    }
+
+    assert!(Moment::<FixedOffset>::parse("Tuesday May 20 2019", "dddd MMMM D YYYY").is_err());
+}
+
+#[test]
+fn test_parse_formats() {
+    let formats = ["MM-DD-YYYY", "YYYY-MM-DD", "MMMM Do YYYY"];
+
+    let (moment, index) =
+        Moment::<FixedOffset>::parse_formats("2019-05-20", &formats, ParseMode::Strict).unwrap();
+    assert_eq!(index, 1);
+    assert_eq!(moment.format(String::from("L")), String::from("05/20/2019"));
+
+    let (moment, index) = Moment::<FixedOffset>::parse_formats(
+        "May 20th 2019 at noon",
+        &formats,
+        ParseMode::Forgiving,
+    )
+    .unwrap();
+    assert_eq!(index, 2);
+    assert_eq!(moment.format(String::from("L")), String::from("05/20/2019"));
+
+    let (_, index) =
+        Moment::<FixedOffset>::parse_formats("2019/05/20", &formats, ParseMode::Forgiving).unwrap();
+    assert_eq!(index, 1);
+
+    assert!(
+        Moment::<FixedOffset>::parse_formats("2019/05/20", &formats, ParseMode::Strict).is_err()
+    );
+    assert!(
+        Moment::<FixedOffset>::parse_formats("2019-5-20", &formats, ParseMode::Strict).is_err()
+    );
+    assert!(Moment::<FixedOffset>::parse_formats(
+        "May 20th 2019 at noon",
+        &formats,
+        ParseMode::Strict
+    )
+    .is_err());
+}
+
+#[test]
+fn test_errors() {
+    assert_eq!(
+        Moment::<FixedOffset>::parse_formats(
+            "2019-05-20 trailing",
+            &["YYYY-MM-DD"],
+            ParseMode::Strict
+        )
+        .unwrap_err(),
+        MomentError::Parse {
+            input: String::from("2019-05-20 trailing"),
+            format: Some(String::from("YYYY-MM-DD")),
+            offset: 10,
+        }
+    );
+    assert_eq!(
+        Moment::<FixedOffset>::parse("2019-13-20", "YYYY-MM-DD").unwrap_err(),
+        MomentError::OutOfRange {
+            component: "month",
+            value: 13,
+        }
+    );
+
+    let moment = Moment::<FixedOffset>::new("2019-05-20T15:04:05+00:00", None).unwrap();
+    assert_eq!(
+        moment.checked_format(String::from("HH:mm z")).unwrap(),
+        String::from("15:04 +00:00")
+    );
+    assert_eq!(
+        moment
+            .checked_format(String::from("YYYY [W]W HH:mm"))
+            .unwrap_err(),
+        MomentError::UnsupportedToken(String::from("W"))
+    );
+    assert_eq!(
+        moment.format(String::from("YYYY [W]W HH:mm")),
+        String::from("2019 WW 15:04")
+    );
+    let format = LOCALE_EN_US.compile_format("Y-MM-DD hmm");
+    assert_eq!(
+        moment.checked_format_compiled(&format).unwrap_err(),
+        MomentError::UnsupportedToken(String::from("hmm"))
+    );
+    assert_eq!(
+        moment.clone().checked_add(Duration::MAX).unwrap_err(),
+        MomentError::Overflow
+    );
+
+    let mut durations = HashSet::new();
+    durations.insert((i64::MAX, UnitOfTime::Quarter));
+    assert_eq!(
+        checked_duration(durations).unwrap_err(),
+        MomentError::Overflow
+    );
+
+    let start_of_week = moment.clone().checked_start_of(UnitOfTime::Week).unwrap();
+    assert_eq!(
+        start_of_week.to_rfc3339(),
+        String::from("2019-05-19T00:00:00+00:00")
+    );
+    let start_of_quarter = moment.checked_start_of(UnitOfTime::Quarter).unwrap();
+    assert_eq!(
+        start_of_quarter.to_rfc3339(),
+        String::from("2019-04-01T00:00:00+00:00")
+    );
+}
+
+#[test]
+fn test_moment_duration() {
+    let moment = Moment::<FixedOffset>::new("2019-01-31T10:00:00+01:00", None).unwrap();
+    let one_month = MomentDuration {
+        months: 1,
+        ..MomentDuration::default()
+    };
+
+    let next_month = moment.clone().add(one_month);
+    assert_eq!(
+        next_month.to_rfc3339(),
+        String::from("2019-02-28T10:00:00+01:00")
+    );
+    assert_eq!(
+        next_month.subtract(one_month).to_rfc3339(),
+        String::from("2019-01-28T10:00:00+01:00")
+    );
+
+    let mixed = MomentDuration {
+        years: 1,
+        months: 1,
+        days: 1,
+        time: Duration::hours(2),
+        ..MomentDuration::default()
+    };
+    assert_eq!(
+        moment.clone().add(mixed).to_rfc3339(),
+        String::from("2020-03-01T12:00:00+01:00")
+    );
+
+    assert_eq!(
+        moment.clone().end_of(UnitOfTime::Month).to_rfc3339(),
+        String::from("2019-01-31T23:59:59.999999999+01:00")
+    );
+    assert_eq!(
+        moment.end_of(UnitOfTime::Year).to_rfc3339(),
+        String::from("2019-12-31T23:59:59.999999999+01:00")
+    );
+}
+
+#[test]
+fn test_moment_duration_builder() {
+    let built = MomentDuration::new().days(5).hours(2);
+    assert_eq!(
+        built,
+        MomentDuration::from_units(vec![(5, UnitOfTime::Day), (2, UnitOfTime::Hour)])
+    );
+    assert_eq!(built, MomentDuration::from_compact("5d2h").unwrap());
+    assert_eq!(built, MomentDuration::from_compact(" 5d 1h 60m ").unwrap());
+
+    assert_eq!(
+        MomentDuration::from_units(vec![(1, UnitOfTime::Day), (1, UnitOfTime::Day)]),
+        MomentDuration::new().days(2)
+    );
+    assert_eq!(
+        MomentDuration::from_compact("1y-2M3Q").unwrap(),
+        MomentDuration::new().years(1).months(7)
+    );
+    assert_eq!(
+        MomentDuration::from_compact("5d2x").unwrap_err(),
+        MomentError::Parse {
+            input: String::from("5d2x"),
+            format: Some(String::from("<number><y|Q|M|w|d|h|m|s|ms|us|ns>...")),
+            offset: 2,
+        }
+    );
+
+    let moment = Moment::<FixedOffset>::new("2019-05-20T00:00:00+00:00", None).unwrap();
+    assert_eq!(
+        moment
+            .add(MomentDuration::new().days(5).hours(2))
+            .to_rfc3339(),
+        String::from("2019-05-25T02:00:00+00:00")
+    );
+}
+
+#[test]
+fn test_moment_duration_iso_8601() {
+    let parsed = MomentDuration::from_iso_8601("P1Y2M3DT4H5M6.789S").unwrap();
+    assert_eq!(
+        parsed,
+        MomentDuration::new()
+            .years(1)
+            .months(2)
+            .days(3)
+            .hours(4)
+            .minutes(5)
+            .seconds(6)
+            .milliseconds(789)
+    );
+    assert_eq!(parsed.to_iso_string(), String::from("P1Y2M3DT4H5M6.789S"));
+
+    assert_eq!(
+        "P3W".parse::<MomentDuration>().unwrap(),
+        MomentDuration::new().weeks(3)
+    );
+    assert_eq!(
+        MomentDuration::new().weeks(3).to_string(),
+        String::from("P21D")
+    );
+    assert_eq!(
+        "-PT90M".parse::<MomentDuration>().unwrap().to_string(),
+        String::from("-PT1H30M")
+    );
+    assert_eq!(
+        "P0.5Y".parse::<MomentDuration>().unwrap(),
+        MomentDuration::new().months(6)
+    );
+    assert_eq!(
+        "P1.5D".parse::<MomentDuration>().unwrap(),
+        MomentDuration::new().days(1).hours(12)
+    );
+    assert_eq!(
+        "P14M".parse::<MomentDuration>().unwrap().to_string(),
+        String::from("P1Y2M")
+    );
+    assert_eq!(
+        MomentDuration::new().months(1).days(-40).to_string(),
+        String::from("-P-1M40D")
+    );
+    assert_eq!(MomentDuration::new().to_string(), String::from("P0D"));
+    for (duration, iso_string) in [
+        (MomentDuration::new().days(1).hours(-24), "P1DT-24H"),
+        (MomentDuration::new().months(1).days(-30), "P1M-30D"),
+        (MomentDuration::new().days(-1).hours(24), "P-1DT24H"),
+    ]
+    .iter()
+    {
+        assert_eq!(duration.to_string(), *iso_string);
+        assert_eq!(iso_string.parse::<MomentDuration>().unwrap(), *duration);
+    }
+
+    assert!("P1.5DT1H".parse::<MomentDuration>().is_err());
+    assert!("P1D2Y".parse::<MomentDuration>().is_err());
+    assert!("PT".parse::<MomentDuration>().is_err());
+    assert_eq!(
+        "P1X".parse::<MomentDuration>().unwrap_err(),
+        MomentError::Parse {
+            input: String::from("P1X"),
+            format: Some(String::from("PnYnMnWnDTnHnMnS")),
+            offset: 2,
+        }
+    );
+}
+
+#[test]
+fn test_moment_duration_normalize() {
+    let ninety_minutes = MomentDuration::new().minutes(90);
+    assert_eq!(ninety_minutes.get(UnitOfTime::Hour), 1);
+    assert_eq!(ninety_minutes.get(UnitOfTime::Minute), 30);
+    assert_eq!(ninety_minutes.as_unit(UnitOfTime::Hour), 1.5);
+    assert_eq!(ninety_minutes.as_unit(UnitOfTime::Minute), 90_f64);
+
+    assert_eq!(
+        MomentDuration::new().months(14).normalize(),
+        MomentDuration::new().years(1).months(2)
+    );
+    assert_eq!(
+        MomentDuration::new().hours(50).normalize(),
+        MomentDuration::new().days(2).hours(2)
+    );
+    assert_eq!(
+        MomentDuration::new().weeks(1).days(3).normalize(),
+        MomentDuration::new().days(10)
+    );
+    assert_eq!(MomentDuration::new().days(10).get(UnitOfTime::Week), 1);
+    assert_eq!(MomentDuration::new().days(45).get(UnitOfTime::Month), 1);
+    assert_eq!(MomentDuration::new().days(45).get(UnitOfTime::Day), 14);
+    assert_eq!(
+        MomentDuration::new().seconds(-3661).normalize(),
+        MomentDuration::new().hours(-1).minutes(-1).seconds(-1)
+    );
+    assert_eq!(
+        MomentDuration::new()
+            .milliseconds(1500)
+            .get(UnitOfTime::Millisecond),
+        500
+    );
+
+    assert_eq!(
+        MomentDuration::new().years(1).as_unit(UnitOfTime::Month),
+        12_f64
+    );
+    assert_eq!(
+        MomentDuration::new().months(6).as_unit(UnitOfTime::Quarter),
+        2_f64
+    );
+    assert_eq!(
+        MomentDuration::new().weeks(2).as_unit(UnitOfTime::Day),
+        14_f64
+    );
+    assert_eq!(
+        MomentDuration::new().months(1).as_unit(UnitOfTime::Day),
+        30_f64
+    );
+}
+
+#[test]
+fn test_relative_time() {
+    let moment = Moment::parse("2020-01-15 12:00:00", "YYYY-MM-DD HH:mm:ss").unwrap();
+    let later = |duration: MomentDuration| moment.clone().add(duration);
+
+    assert_eq!(
+        later(MomentDuration::new().seconds(30)).from(&moment, false, None),
+        String::from("in a few seconds")
+    );
+    assert_eq!(
+        later(MomentDuration::new().seconds(44)).from(&moment, false, None),
+        String::from("in a few seconds")
+    );
+    assert_eq!(
+        later(MomentDuration::new().seconds(45)).from(&moment, false, None),
+        String::from("in a minute")
+    );
+    assert_eq!(
+        later(MomentDuration::new().minutes(44)).from(&moment, false, None),
+        String::from("in 44 minutes")
+    );
+    assert_eq!(
+        moment.from(&later(MomentDuration::new().hours(3)), false, None),
+        String::from("3 hours ago")
+    );
+    assert_eq!(
+        moment.from(&later(MomentDuration::new().hours(22)), false, None),
+        String::from("a day ago")
+    );
+    assert_eq!(
+        moment.to(&later(MomentDuration::new().days(25)), false, None),
+        String::from("in 25 days")
+    );
+    assert_eq!(
+        moment.to(&later(MomentDuration::new().days(26)), true, None),
+        String::from("a month")
+    );
+    assert_eq!(
+        moment.to(&later(MomentDuration::new().months(10)), true, None),
+        String::from("10 months")
+    );
+    assert_eq!(
+        moment.to(&later(MomentDuration::new().months(11)), true, None),
+        String::from("a year")
+    );
+    assert_eq!(
+        later(MomentDuration::new().years(5)).to(&moment, false, None),
+        String::from("5 years ago")
+    );
+    assert_eq!(
+        moment.from(&moment, false, None),
+        String::from("a few seconds ago")
+    );
+
+    let now = Moment::<Utc>::utc(None::<String>, None).unwrap();
+    assert_eq!(
+        now.clone()
+            .subtract(MomentDuration::new().hours(3))
+            .from_now(false, None),
+        String::from("3 hours ago")
+    );
+    assert_eq!(
+        now.add(MomentDuration::new().days(2)).to_now(true, None),
+        String::from("2 days")
+    );
+
+    // The global config is only changed here, so that other tests keep the defaults.
+    let fifty_nine_minutes = later(MomentDuration::new().minutes(59).seconds(40));
+    assert_eq!(
+        fifty_nine_minutes.from(&moment, false, None),
+        String::from("in an hour")
+    );
+    set_relative_time_config(RelativeTimeConfig {
+        thresholds: RelativeTimeThresholds {
+            m: 60_f64,
+            ..RelativeTimeThresholds::default()
+        },
+        rounding: f64::floor,
+    });
+    assert_eq!(
+        fifty_nine_minutes.from(&moment, false, None),
+        String::from("in 59 minutes")
+    );
+    set_relative_time_config(RelativeTimeConfig::default());
+    assert_eq!(
+        relative_time_config().thresholds,
+        RelativeTimeThresholds::default()
+    );
+}
+
+#[test]
+fn test_relative_time_thresholds() {
+    let moment = Moment::parse("2020-01-15 12:00:00", "YYYY-MM-DD HH:mm:ss").unwrap();
+    let later = |duration: MomentDuration| moment.clone().add(duration);
+
+    let seconds_config = RelativeTimeConfig {
+        thresholds: RelativeTimeThresholds {
+            ss: 3_f64,
+            s: 60_f64,
+            ..RelativeTimeThresholds::default()
+        },
+        ..RelativeTimeConfig::default()
+    };
+    assert_eq!(
+        later(MomentDuration::new().seconds(59)).from(&moment, true, Some(&seconds_config)),
+        String::from("59 seconds")
+    );
+    assert_eq!(
+        later(MomentDuration::new().seconds(3)).from(&moment, true, Some(&seconds_config)),
+        String::from("a few seconds")
+    );
+
+    let floor_config = RelativeTimeConfig {
+        rounding: f64::floor,
+        ..RelativeTimeConfig::default()
+    };
+    assert_eq!(
+        later(MomentDuration::new().hours(2).minutes(50)).from(
+            &moment,
+            true,
+            Some(&RelativeTimeConfig::default())
+        ),
+        String::from("3 hours")
+    );
+    assert_eq!(
+        later(MomentDuration::new().hours(2).minutes(50)).from(&moment, true, Some(&floor_config)),
+        String::from("2 hours")
+    );
+
+    let week_config = RelativeTimeConfig {
+        thresholds: RelativeTimeThresholds {
+            d: 7_f64,
+            w: Some(4_f64),
+            ..RelativeTimeThresholds::default()
+        },
+        ..RelativeTimeConfig::default()
+    };
+    assert_eq!(
+        moment.to(
+            &later(MomentDuration::new().days(7)),
+            false,
+            Some(&week_config)
+        ),
+        String::from("in a week")
+    );
+    assert_eq!(
+        moment.from(
+            &later(MomentDuration::new().days(15)),
+            false,
+            Some(&week_config)
+        ),
+        String::from("2 weeks ago")
+    );
+    assert_eq!(
+        moment.to(
+            &later(MomentDuration::new().days(26)),
+            true,
+            Some(&week_config)
+        ),
+        String::from("a month")
+    );
+}
+
+#[test]
+fn test_named_time_zones() {
+    let new_york = Tz::from_name("America/New_York").unwrap();
+    assert_eq!(new_york.name(), "America/New_York");
+    assert_eq!("us/eastern".parse::<Tz>().unwrap().name(), "US/Eastern");
+    assert!(Tz::names().any(|name| name == "Europe/London"));
+    assert_eq!(
+        Tz::from_name("Mars/Olympus_Mons").unwrap_err(),
+        MomentError::UnknownTimeZone(String::from("Mars/Olympus_Mons"))
+    );
+
+    let winter =
+        Moment::<Tz>::parse_in("2019-01-15 09:30", "YYYY-MM-DD HH:mm", "America/New_York").unwrap();
+    assert_eq!(
+        winter.to_rfc3339(),
+        String::from("2019-01-15T09:30:00-05:00")
+    );
+    assert_eq!(winter.offset().abbreviation(), "EST");
+    let summer =
+        Moment::<Tz>::parse_in("2019-07-15 09:30", "YYYY-MM-DD HH:mm", "America/New_York").unwrap();
+    assert_eq!(
+        summer.to_rfc3339(),
+        String::from("2019-07-15T09:30:00-04:00")
+    );
+    assert_eq!(summer.offset().abbreviation(), "EDT");
+    assert!(summer.offset().is_dst());
+
+    let with_offset = Moment::<Tz>::parse_in(
+        "2019-07-15 09:30 +00:00",
+        "YYYY-MM-DD HH:mm Z",
+        "Europe/London",
+    )
+    .unwrap();
+    assert_eq!(
+        with_offset.format(String::from("HH:mm Z")),
+        String::from("10:30 +01:00")
+    );
+
+    let london = summer.clone().tz("Europe/London").unwrap();
+    assert_eq!(
+        london.to_rfc3339(),
+        String::from("2019-07-15T14:30:00+01:00")
+    );
+    assert_eq!(london.offset().abbreviation(), "BST");
+    assert_eq!(london.time_zone().name(), "Europe/London");
+    assert_eq!(
+        Moment::<FixedOffset>::new("2019-01-15T14:30:00+00:00", None)
+            .unwrap()
+            .tz("Asia/Kolkata")
+            .unwrap()
+            .format(String::from("YYYY-MM-DD HH:mm Z")),
+        String::from("2019-01-15 20:00 +05:30")
+    );
+
+    // 02:30 doesn't exist on the day clocks spring forward, so it is shifted forward an hour.
+    let skipped =
+        Moment::<Tz>::parse_in("2019-03-10 02:30", "YYYY-MM-DD HH:mm", "America/New_York").unwrap();
+    assert_eq!(
+        skipped.to_rfc3339(),
+        String::from("2019-03-10T03:30:00-04:00")
+    );
+    // 01:30 happens twice on the day clocks fall back, so the earlier one is used.
+    let repeated =
+        Moment::<Tz>::parse_in("2019-11-03 01:30", "YYYY-MM-DD HH:mm", "America/New_York").unwrap();
+    assert_eq!(
+        repeated.to_rfc3339(),
+        String::from("2019-11-03T01:30:00-04:00")
+    );
+
+    // Past the precomputed transitions, the zone's current rules still apply.
+    let summer = new_york.with_ymd_and_hms(2150, 7, 1, 12, 0, 0).unwrap();
+    assert_eq!(summer.offset().abbreviation(), "EDT");
+    assert_eq!(
+        summer.to_rfc3339(),
+        String::from("2150-07-01T12:00:00-04:00")
+    );
+    let winter = new_york.with_ymd_and_hms(2150, 12, 1, 12, 0, 0).unwrap();
+    assert_eq!(
+        winter.to_rfc3339(),
+        String::from("2150-12-01T12:00:00-05:00")
+    );
+    assert!(new_york
+        .with_ymd_and_hms(2150, 3, 8, 2, 30, 0)
+        .single()
+        .is_none());
+    let sydney = Tz::from_name("Australia/Sydney").unwrap();
+    assert_eq!(
+        sydney
+            .with_ymd_and_hms(2150, 1, 1, 12, 0, 0)
+            .unwrap()
+            .offset()
+            .abbreviation(),
+        "AEDT"
+    );
+
+    assert!(Moment::<Tz>::new("Australia/Sydney").is_ok());
+    assert!(Moment::<Tz>::new("Nowhere").is_err());
+}
+
+#[test]
+fn test_tzif_time_zones() {
+    let directory = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/zoneinfo");
+    for name in ["America/New_York", "Australia/Sydney", "Europe/Dublin"].iter() {
+        let tzif = TzifZone::from_directory(directory, name).unwrap();
+        let embedded = Tz::from_name(name).unwrap();
+        assert_eq!(tzif.name(), *name);
+
+        let mut instant = Utc.with_ymd_and_hms(1900, 1, 1, 0, 0, 0).unwrap();
+        while instant.year() < 2200 {
+            let tzif_offset = instant.with_timezone(&tzif).offset().clone();
+            let embedded_offset = *instant.with_timezone(&embedded).offset();
+            assert_eq!(
+                tzif_offset.fix(),
+                embedded_offset.fix(),
+                "{} at {}",
+                name,
+                instant
+            );
+            assert_eq!(tzif_offset.abbreviation(), embedded_offset.abbreviation());
+            assert_eq!(
+                tzif_offset.is_dst(),
+                embedded_offset.is_dst(),
+                "{} at {}",
+                name,
+                instant
+            );
+
+            let local = instant.naive_utc();
+            assert_eq!(
+                tzif.from_local_datetime(&local)
+                    .map(|date_time| date_time.fixed_offset()),
+                embedded
+                    .from_local_datetime(&local)
+                    .map(|date_time| date_time.fixed_offset()),
+                "{} at local {}",
+                name,
+                local
+            );
+            instant += Duration::minutes(30 * 60 + 30);
+        }
+    }
+
+    // Past the last transition in the file, the POSIX TZ footer is used.
+    let new_york = TzifZone::from_directory(directory, "America/New_York").unwrap();
+    let summer = new_york.with_ymd_and_hms(2150, 7, 1, 12, 0, 0).unwrap();
+    assert_eq!(summer.offset().abbreviation(), "EDT");
+    assert_eq!(
+        summer.to_rfc3339(),
+        String::from("2150-07-01T12:00:00-04:00")
+    );
+    let winter = new_york.with_ymd_and_hms(2150, 12, 1, 12, 0, 0).unwrap();
+    assert_eq!(
+        winter.to_rfc3339(),
+        String::from("2150-12-01T12:00:00-05:00")
+    );
+    assert!(new_york
+        .with_ymd_and_hms(2150, 3, 8, 2, 30, 0)
+        .single()
+        .is_none());
+
+    let moment = Moment::<FixedOffset>::new("2019-07-15T14:30:00+00:00", None)
+        .unwrap()
+        .with_time_zone(&new_york);
+    assert_eq!(
+        moment.format(String::from("YYYY-MM-DD HH:mm Z")),
+        String::from("2019-07-15 10:30 -04:00")
+    );
+    let summer_moment: Moment<TzifZone> = summer.into();
+    assert_eq!(
+        summer_moment.format(String::from("HH:mm ZZ")),
+        String::from("12:00 -0400")
+    );
+
+    assert_eq!(
+        TzifZone::from_directory(directory, "Mars/Olympus_Mons").unwrap_err(),
+        MomentError::UnknownTimeZone(String::from("Mars/Olympus_Mons"))
+    );
+    assert_eq!(
+        TzifZone::from_directory(directory, "../test.rs").unwrap_err(),
+        MomentError::UnknownTimeZone(String::from("../test.rs"))
+    );
+    assert_eq!(
+        TzifZone::from_bytes("Corrupt", b"TZif2 not really").unwrap_err(),
+        MomentError::InvalidTimeZone {
+            name: String::from("Corrupt"),
+            reason: "the header is truncated",
+        }
+    );
+    assert_eq!(
+        TzifZone::from_bytes("Empty", b"").unwrap_err(),
+        MomentError::InvalidTimeZone {
+            name: String::from("Empty"),
+            reason: "the file is not a TZif file",
+        }
+    );
+
+    // Counts far larger than the file are rejected before anything is allocated.
+    for version in [b'\0', b'2'].iter() {
+        let mut header = b"TZif".to_vec();
+        header.push(*version);
+        header.extend_from_slice(&[0; 15]);
+        for count in [0_u32, 0, 0, u32::MAX, u32::MAX, u32::MAX].iter() {
+            header.extend_from_slice(&count.to_be_bytes());
+        }
+        assert_eq!(header.len(), 44);
+        assert_eq!(
+            TzifZone::from_bytes("Huge", &header).unwrap_err(),
+            MomentError::InvalidTimeZone {
+                name: String::from("Huge"),
+                reason: "the data block is truncated",
+            }
+        );
+    }
+}
+
+// Compares daylight saving time with the system zoneinfo files around every embedded transition.
+// Distributions may build those with `backzone`, which changes the history of some zones, so only
+// instants where both agree on the offset and abbreviation are compared. The test is skipped
+// where the files aren't installed.
+#[test]
+fn test_tzif_daylight_saving_time_matches_embedded() {
+    if TzifZone::from_name("America/New_York").is_err() {
+        return;
+    }
+    let start = Utc.with_ymd_and_hms(1800, 1, 1, 0, 0, 0).unwrap();
+    let end = Utc.with_ymd_and_hms(2100, 1, 1, 0, 0, 0).unwrap();
+    for name in Tz::names() {
+        let (embedded, tzif) = match (Tz::from_name(name), TzifZone::from_name(name)) {
+            (Ok(embedded), Ok(tzif)) => (embedded, tzif),
+            _ => continue,
+        };
+        for transition in embedded.transitions(&start, &end) {
+            for (instant, offset) in [
+                (
+                    transition.instant() - Duration::seconds(1),
+                    transition.old_offset(),
+                ),
+                (transition.instant(), transition.new_offset()),
+            ]
+            .iter()
+            {
+                let tzif_offset = instant.with_timezone(&tzif).offset().clone();
+                if tzif_offset.fix() == offset.fix()
+                    && tzif_offset.abbreviation() == offset.abbreviation()
+                {
+                    assert_eq!(
+                        tzif_offset.is_dst(),
+                        offset.is_dst(),
+                        "{} at {}",
+                        name,
+                        instant
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_posix_time_zones() {
+    let eastern: PosixTz = "EST5EDT,M3.2.0,M11.1.0".parse().unwrap();
+    let new_york = Tz::from_name("America/New_York").unwrap();
+    let sydney_rules = PosixTz::parse("AEST-10AEDT,M10.1.0,M4.1.0/3").unwrap();
+    let sydney = Tz::from_name("Australia/Sydney").unwrap();
+    let mut instant = Utc.with_ymd_and_hms(2010, 1, 1, 0, 0, 0).unwrap();
+    while instant.year() < 2030 {
+        for (posix, tz) in [(&eastern, new_york), (&sydney_rules, sydney)].iter() {
+            let expected = instant.with_timezone(tz);
+            let actual = instant.with_timezone(*posix);
+            assert_eq!(actual.offset().fix(), expected.offset().fix());
+            assert_eq!(
+                actual.offset().abbreviation(),
+                expected.offset().abbreviation()
+            );
+            assert_eq!(actual.offset().is_dst(), expected.offset().is_dst());
+        }
+        instant += Duration::hours(19);
+    }
+
+    assert_eq!(
+        eastern
+            .with_ymd_and_hms(2019, 11, 3, 1, 30, 0)
+            .earliest()
+            .unwrap()
+            .to_rfc3339(),
+        String::from("2019-11-03T01:30:00-04:00")
+    );
+    assert!(eastern
+        .with_ymd_and_hms(2019, 3, 10, 2, 30, 0)
+        .single()
+        .is_none());
+    // Without rules, the US rules are assumed.
+    assert_eq!(PosixTz::parse("EST5EDT").unwrap().tz_string(), "EST5EDT");
+    assert_eq!(
+        PosixTz::parse("EST5EDT")
+            .unwrap()
+            .with_ymd_and_hms(2019, 7, 1, 12, 0, 0)
+            .unwrap()
+            .offset()
+            .abbreviation(),
+        "EDT"
+    );
+
+    let india = PosixTz::parse("<+0530>-5:30").unwrap();
+    let moment: Moment<PosixTz> = india
+        .with_ymd_and_hms(2019, 7, 15, 20, 0, 0)
+        .unwrap()
+        .into();
+    assert_eq!(
+        moment.format(String::from("YYYY-MM-DD HH:mm Z")),
+        String::from("2019-07-15 20:00 +05:30")
+    );
+    assert_eq!(moment.offset().abbreviation(), "+0530");
+    assert_eq!(
+        Moment::<FixedOffset>::new("2019-07-15T14:30:00+00:00", None)
+            .unwrap()
+            .with_time_zone(&eastern)
+            .format(String::from("HH:mm ZZ")),
+        String::from("10:30 -0400")
+    );
+
+    assert!(PosixTz::parse("America/New_York").is_err());
+    assert!(PosixTz::parse("EST5EDT,M3.2.0").is_err());
+    assert!(PosixTz::parse("ES5").is_err());
+}
+
+#[test]
+fn test_time_zone_arithmetic() {
+    let new_york =
+        |date: &str| Moment::<Tz>::parse_in(date, "YYYY-MM-DD HH:mm", "America/New_York").unwrap();
+
+    // Days, weeks, months and years keep the wall-clock time across daylight saving time.
+    let before_spring = new_york("2019-03-09 12:00");
+    assert_eq!(
+        before_spring
+            .clone()
+            .add(duration(vec![(1, UnitOfTime::Day)]))
+            .to_rfc3339(),
+        String::from("2019-03-10T12:00:00-04:00")
+    );
+    assert_eq!(
+        before_spring
+            .clone()
+            .add(duration(vec![(1, UnitOfTime::Week)]))
+            .to_rfc3339(),
+        String::from("2019-03-16T12:00:00-04:00")
+    );
+    assert_eq!(
+        new_york("2019-10-15 12:00")
+            .add(duration(vec![(1, UnitOfTime::Month)]))
+            .to_rfc3339(),
+        String::from("2019-11-15T12:00:00-05:00")
+    );
+    assert_eq!(
+        new_york("2019-03-10 12:00")
+            .subtract(duration(vec![(1, UnitOfTime::Day)]))
+            .to_rfc3339(),
+        String::from("2019-03-09T12:00:00-05:00")
+    );
+
+    // Hours and smaller units are absolute.
+    assert_eq!(
+        before_spring
+            .add(duration(vec![(24, UnitOfTime::Hour)]))
+            .to_rfc3339(),
+        String::from("2019-03-10T13:00:00-04:00")
+    );
+    assert_eq!(
+        new_york("2019-11-03 00:30")
+            .add(duration(vec![(2, UnitOfTime::Hour)]))
+            .to_rfc3339(),
+        String::from("2019-11-03T01:30:00-05:00")
+    );
+
+    // Times in a gap are shifted forward and times in an overlap take the earlier instant.
+    assert_eq!(
+        new_york("2019-03-09 02:30")
+            .add(duration(vec![(1, UnitOfTime::Day)]))
+            .to_rfc3339(),
+        String::from("2019-03-10T03:30:00-04:00")
+    );
+    assert_eq!(
+        new_york("2019-02-10 02:30")
+            .add(duration(vec![(1, UnitOfTime::Month)]))
+            .to_rfc3339(),
+        String::from("2019-03-10T03:30:00-04:00")
+    );
+    assert_eq!(
+        new_york("2019-11-02 01:30")
+            .add(duration(vec![(1, UnitOfTime::Day)]))
+            .to_rfc3339(),
+        String::from("2019-11-03T01:30:00-04:00")
+    );
+
+    assert_eq!(
+        new_york("2019-03-13 10:00")
+            .start_of(UnitOfTime::Week)
+            .to_rfc3339(),
+        String::from("2019-03-10T00:00:00-05:00")
+    );
+    assert_eq!(
+        new_york("2019-03-10 10:00")
+            .end_of(UnitOfTime::Day)
+            .format(String::from("YYYY-MM-DD HH:mm:ss.SSS Z")),
+        String::from("2019-03-10 23:59:59.999 -04:00")
+    );
+}
+
+#[test]
+fn test_disambiguation() {
+    let new_york = Tz::from_name("America/New_York").unwrap();
+    let sao_paulo = Tz::from_name("America/Sao_Paulo").unwrap();
+    let local = |date: &str| NaiveDateTime::parse_from_str(date, "%Y-%m-%d %H:%M").unwrap();
+    let resolve = |date: &str, time_zone: &Tz, disambiguation| {
+        Moment::from_local(&local(date), time_zone, disambiguation)
+            .map(|moment| moment.to_rfc3339())
+    };
+
+    // 2:30 is skipped in New York on 2019-03-10 and 1:30 is repeated on 2019-11-03.
+    for (disambiguation, skipped, repeated) in [
+        (
+            Disambiguation::Compatible,
+            "2019-03-10T03:30:00-04:00",
+            "2019-11-03T01:30:00-04:00",
+        ),
+        (
+            Disambiguation::Earlier,
+            "2019-03-10T01:30:00-05:00",
+            "2019-11-03T01:30:00-04:00",
+        ),
+        (
+            Disambiguation::Later,
+            "2019-03-10T03:30:00-04:00",
+            "2019-11-03T01:30:00-05:00",
+        ),
+    ]
+    .iter()
+    {
+        assert_eq!(
+            resolve("2019-03-10 02:30", &new_york, *disambiguation).unwrap(),
+            String::from(*skipped)
+        );
+        assert_eq!(
+            resolve("2019-11-03 01:30", &new_york, *disambiguation).unwrap(),
+            String::from(*repeated)
+        );
+    }
+    assert_eq!(
+        resolve("2019-03-10 02:30", &new_york, Disambiguation::Reject).unwrap_err(),
+        MomentError::NonexistentLocalTime(String::from("2019-03-10 02:30:00"))
+    );
+    assert_eq!(
+        resolve("2019-11-03 01:30", &new_york, Disambiguation::Reject).unwrap_err(),
+        MomentError::AmbiguousLocalTime(String::from("2019-11-03 01:30:00"))
+    );
+
+    // Midnight was skipped in São Paulo on 2018-11-04.
+    let transition_day = Moment::from_local(
+        &local("2018-11-04 12:00"),
+        &sao_paulo,
+        Disambiguation::default(),
+    )
+    .unwrap();
+    assert_eq!(
+        transition_day
+            .clone()
+            .start_of(UnitOfTime::Day)
+            .to_rfc3339(),
+        String::from("2018-11-04T01:00:00-02:00")
+    );
+    assert_eq!(
+        transition_day
+            .clone()
+            .disambiguation(Disambiguation::Earlier)
+            .start_of(UnitOfTime::Day)
+            .to_rfc3339(),
+        String::from("2018-11-03T23:00:00-03:00")
+    );
+    assert_eq!(
+        transition_day
+            .clone()
+            .disambiguation(Disambiguation::Reject)
+            .checked_start_of(UnitOfTime::Day)
+            .unwrap_err(),
+        MomentError::NonexistentLocalTime(String::from("2018-11-04 00:00:00"))
+    );
+    for (unit, end) in [
+        (UnitOfTime::Day, "2018-11-04T23:59:59.999999999-02:00"),
+        (UnitOfTime::Week, "2018-11-10T23:59:59.999999999-02:00"),
+    ]
+    .iter()
+    {
+        assert_eq!(
+            transition_day.clone().end_of(*unit).to_rfc3339(),
+            String::from(*end)
+        );
+        assert_eq!(
+            transition_day
+                .clone()
+                .disambiguation(Disambiguation::Reject)
+                .end_of(*unit)
+                .to_rfc3339(),
+            String::from(*end)
+        );
+    }
+    assert_eq!(
+        transition_day
+            .subtract(duration(vec![(1, UnitOfTime::Day)]))
+            .disambiguation(Disambiguation::Reject)
+            .end_of(UnitOfTime::Day)
+            .format(String::from("YYYY-MM-DD HH:mm:ss.SSS Z")),
+        String::from("2018-11-03 23:59:59.999 -03:00")
+    );
+
+    // The repeated hour keeps its offset when truncated.
+    let second_one_thirty =
+        Moment::from_local(&local("2019-11-03 01:30"), &new_york, Disambiguation::Later).unwrap();
+    assert_eq!(
+        second_one_thirty
+            .clone()
+            .start_of(UnitOfTime::Hour)
+            .to_rfc3339(),
+        String::from("2019-11-03T01:00:00-05:00")
+    );
+
+    let day_before = Moment::from_local(
+        &local("2019-03-09 02:30"),
+        &new_york,
+        Disambiguation::Reject,
+    )
+    .unwrap();
+    assert_eq!(
+        day_before
+            .clone()
+            .checked_add(duration(vec![(1, UnitOfTime::Day)]))
+            .unwrap_err(),
+        MomentError::NonexistentLocalTime(String::from("2019-03-10 02:30:00"))
+    );
+
+    // Setters work on the wall clock too.
+    let midnight = Moment::from_local(
+        &local("2019-03-10 00:00"),
+        &new_york,
+        Disambiguation::default(),
+    )
+    .unwrap();
+    assert_eq!(
+        midnight.clone().set(UnitOfTime::Hour, 2).to_rfc3339(),
+        String::from("2019-03-10T03:00:00-04:00")
+    );
+    assert_eq!(
+        midnight
+            .clone()
+            .disambiguation(Disambiguation::Earlier)
+            .set(UnitOfTime::Hour, 2)
+            .to_rfc3339(),
+        String::from("2019-03-10T01:00:00-05:00")
+    );
+    assert_eq!(
+        day_before.clone().set(UnitOfTime::Day, 31).to_rfc3339(),
+        String::from("2019-03-31T02:30:00-04:00")
+    );
+    assert_eq!(
+        day_before
+            .clone()
+            .set(UnitOfTime::Day, 31)
+            .set(UnitOfTime::Month, 1)
+            .to_rfc3339(),
+        String::from("2019-02-28T02:30:00-05:00")
+    );
+    assert_eq!(
+        day_before.clone().set(UnitOfTime::Year, 2020).to_rfc3339(),
+        String::from("2020-03-09T02:30:00-04:00")
+    );
+    assert_eq!(
+        day_before
+            .clone()
+            .set(UnitOfTime::Millisecond, 250)
+            .format(String::from("HH:mm:ss.SSS")),
+        String::from("02:30:00.250")
+    );
+    assert_eq!(
+        day_before.checked_set(UnitOfTime::Month, 12).unwrap_err(),
+        MomentError::OutOfRange {
+            component: "month",
+            value: 12,
+        }
+    );
+}
+
+#[test]
+fn test_time_zone_names() {
+    let format = || String::from("YYYY-MM-DD HH:mm z [(]zz[)]");
+    let winter =
+        Moment::<Tz>::parse_in("2019-01-15 12:00", "YYYY-MM-DD HH:mm", "America/New_York").unwrap();
+    assert_eq!(
+        winter.format(format()),
+        String::from("2019-01-15 12:00 EST (Eastern Standard Time)")
+    );
+    assert_eq!(
+        winter
+            .clone()
+            .add(duration(vec![(6, UnitOfTime::Month)]))
+            .format(format()),
+        String::from("2019-07-15 12:00 EDT (Eastern Daylight Time)")
+    );
+
+    let london = winter.clone().tz("Europe/London").unwrap();
+    assert_eq!(
+        london
+            .clone()
+            .add(duration(vec![(6, UnitOfTime::Month)]))
+            .format(format()),
+        String::from("2019-07-15 17:00 BST (British Summer Time)")
+    );
+    assert_eq!(
+        london.locale(LOCALE_EN_GB.clone()).format(format()),
+        String::from("2019-01-15 17:00 GMT (Greenwich Mean Time)")
+    );
+
+    assert_eq!(
+        metazone("America/Indiana/Indianapolis"),
+        Some("America_Eastern")
+    );
+    assert_eq!(metazone("US/Pacific"), Some("America_Pacific"));
+    assert_eq!(metazone("Asia/Taipei"), None);
+
+    // Zones that share an abbreviation keep their own long names.
+    assert_eq!(
+        winter.clone().tz("Asia/Shanghai").unwrap().format(format()),
+        String::from("2019-01-16 01:00 CST (China Standard Time)")
+    );
+    assert_eq!(
+        winter.clone().tz("US/Central").unwrap().format(format()),
+        String::from("2019-01-15 11:00 CST (Central Standard Time)")
+    );
+
+    // Abbreviations without a long name are used for both tokens.
+    assert_eq!(
+        winter.clone().tz("Asia/Taipei").unwrap().format(format()),
+        String::from("2019-01-16 01:00 CST (CST)")
+    );
+    assert_eq!(
+        winter
+            .clone()
+            .add(duration(vec![(6, UnitOfTime::Month)]))
+            .tz("America/Havana")
+            .unwrap()
+            .format(format()),
+        String::from("2019-07-15 12:00 CDT (CDT)")
+    );
+    assert_eq!(
+        winter.clone().tz("Asia/Kolkata").unwrap().format(format()),
+        String::from("2019-01-15 22:30 IST (IST)")
+    );
+    assert_eq!(
+        winter.clone().tz("Asia/Dubai").unwrap().format(format()),
+        String::from("2019-01-15 21:00 +04 (+04)")
+    );
+
+    let posix = winter
+        .clone()
+        .with_time_zone(&PosixTz::parse("AEST-10AEDT,M10.1.0,M4.1.0/3").unwrap());
+    assert_eq!(
+        posix.format(String::from("HH:mm z")),
+        String::from("04:00 AEDT")
+    );
+    assert_eq!(
+        winter.clone().with_time_zone(&Utc).format(format()),
+        String::from("2019-01-15 17:00 UTC (Coordinated Universal Time)")
+    );
+    assert_eq!(
+        winter
+            .with_time_zone(&FixedOffset::east_opt(5 * 3600 + 1800).unwrap())
+            .format(format()),
+        String::from("2019-01-15 22:30 +05:30 (+05:30)")
+    );
+}
+
+#[test]
+fn test_parse_time_zone_abbreviations() {
+    let format = "ddd MMM DD HH:mm:ss z YYYY";
+    let moment = Moment::parse("Mon May 20 10:00:00 PDT 2019", format).unwrap();
+    assert_eq!(
+        moment.to_rfc3339(),
+        String::from("2019-05-20T10:00:00-07:00")
+    );
+    // Ambiguous abbreviations use the default table's choice.
+    assert_eq!(
+        Moment::parse("Mon May 20 10:00:00 cst 2019", format)
+            .unwrap()
+            .to_rfc3339(),
+        String::from("2019-05-20T10:00:00-06:00")
+    );
+    assert_eq!(
+        Moment::parse("Mon May 20 10:00:00 IST 2019", format)
+            .unwrap()
+            .to_rfc3339(),
+        String::from("2019-05-20T10:00:00+05:30")
+    );
+    assert!(
+        Moment::parse_formats("Mon May 20 10:00:00 XYZ 2019", &[format], ParseMode::Strict)
+            .is_err()
+    );
+    assert_eq!(
+        Moment::parse("2019-05-20 10:00 +0530", "YYYY-MM-DD HH:mm ZZ")
+            .unwrap()
+            .to_rfc3339(),
+        String::from("2019-05-20T10:00:00+05:30")
+    );
+
+    let mut abbreviations = TimeZoneAbbreviations::default();
+    assert_eq!(abbreviations.insert("IST", 3600), Some(19800));
+    abbreviations.insert("XYZ", -3600);
+    set_time_zone_abbreviations(abbreviations.clone());
+    assert_eq!(time_zone_abbreviations(), abbreviations);
+    let irish = Moment::parse("Mon May 20 10:00:00 IST 2019", format);
+    let custom = Moment::parse("Mon May 20 10:00:00 XYZ 2019", format);
+    set_time_zone_abbreviations(TimeZoneAbbreviations::default());
+    assert_eq!(
+        irish.unwrap().to_rfc3339(),
+        String::from("2019-05-20T10:00:00+01:00")
+    );
+    assert_eq!(
+        custom.unwrap().to_rfc3339(),
+        String::from("2019-05-20T10:00:00-01:00")
+    );
+
+    let utc = Moment::<FixedOffset>::new("2019-05-20T10:00:00+00:00", None).unwrap();
+    assert_eq!(
+        utc.clone()
+            .utc_offset("+05:30", false)
+            .unwrap()
+            .to_rfc3339(),
+        String::from("2019-05-20T15:30:00+05:30")
+    );
+    assert_eq!(
+        utc.clone().utc_offset("-0800", true).unwrap().to_rfc3339(),
+        String::from("2019-05-20T10:00:00-08:00")
+    );
+    assert_eq!(
+        utc.clone().utc_offset(-480, false).unwrap().to_rfc3339(),
+        String::from("2019-05-20T02:00:00-08:00")
+    );
+    // Like Moment.js, small numbers are hours.
+    assert_eq!(
+        utc.clone().utc_offset(3, false).unwrap().to_rfc3339(),
+        String::from("2019-05-20T13:00:00+03:00")
+    );
+    assert_eq!(
+        utc.clone()
+            .utc_offset("2019-05-20 10:00 +0100", false)
+            .unwrap()
+            .to_rfc3339(),
+        String::from("2019-05-20T11:00:00+01:00")
+    );
+    assert_eq!(
+        utc.clone()
+            .utc_offset(UtcOffset::String(String::from("nowhere")), false)
+            .unwrap_err(),
+        MomentError::Parse {
+            input: String::from("nowhere"),
+            format: Some(String::from("Z")),
+            offset: 0,
+        }
+    );
+    assert!(utc.utc_offset(2000, false).is_err());
+}
+
+#[test]
+fn test_time_zone_transitions() {
+    let new_york =
+        |date: &str| Moment::<Tz>::parse_in(date, "YYYY-MM-DD HH:mm", "America/New_York").unwrap();
+    let start = new_york("2019-01-01 00:00");
+    let transitions = start.transitions_until(&new_york("2020-01-01 00:00"));
+    assert_eq!(transitions.len(), 2);
+    let spring = transitions[0];
+    assert_eq!(
+        spring.instant().to_rfc3339(),
+        String::from("2019-03-10T07:00:00+00:00")
+    );
+    assert_eq!(spring.old_offset().abbreviation(), "EST");
+    assert!(!spring.old_offset().is_dst());
+    assert_eq!(spring.new_offset().abbreviation(), "EDT");
+    assert!(spring.new_offset().is_dst());
+    assert_eq!(spring.new_offset().fix().local_minus_utc(), -4 * 3600);
+    assert_eq!(
+        transitions[1].instant().to_rfc3339(),
+        String::from("2019-11-03T06:00:00+00:00")
+    );
+    assert_eq!(transitions[1].new_offset().abbreviation(), "EST");
+
+    // The range is half-open and only includes instants at or after the start.
+    let at_transition: Moment<Tz> = spring.instant().with_timezone(&start.time_zone()).into();
+    assert_eq!(at_transition.transitions_until(&start).len(), 0);
+    assert_eq!(
+        at_transition
+            .transitions_until(&new_york("2019-11-03 01:00"))
+            .len(),
+        1
+    );
+
+    // Local mean time before standard time was adopted in 1883.
+    let tz = Tz::from_name("America/New_York").unwrap();
+    let history = tz.transitions(
+        &Utc.with_ymd_and_hms(1800, 1, 1, 0, 0, 0).unwrap(),
+        &Utc.with_ymd_and_hms(1900, 1, 1, 0, 0, 0).unwrap(),
+    );
+    assert_eq!(history.len(), 1);
+    assert_eq!(history[0].old_offset().abbreviation(), "LMT");
+    assert_eq!(history[0].old_offset().fix().local_minus_utc(), -17762);
+    assert_eq!(history[0].new_offset().abbreviation(), "EST");
+    assert_eq!(
+        history[0].instant().to_rfc3339(),
+        String::from("1883-11-18T17:00:00+00:00")
+    );
+
+    // Past the precomputed transitions in 2100, they follow the zone's current rules.
+    let transitions_around_2101 = |name: &str| {
+        Tz::from_name(name)
+            .unwrap()
+            .transitions(
+                &Utc.with_ymd_and_hms(2100, 6, 1, 0, 0, 0).unwrap(),
+                &Utc.with_ymd_and_hms(2102, 1, 1, 0, 0, 0).unwrap(),
+            )
+            .iter()
+            .map(|transition| {
+                format!(
+                    "{} {} {}",
+                    transition.instant().to_rfc3339(),
+                    transition.old_offset(),
+                    transition.new_offset()
+                )
+            })
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(
+        transitions_around_2101("America/New_York"),
+        vec![
+            "2100-11-07T06:00:00+00:00 EDT EST",
+            "2101-03-13T07:00:00+00:00 EST EDT",
+            "2101-11-06T06:00:00+00:00 EDT EST",
+        ]
+    );
+    assert_eq!(
+        transitions_around_2101("Australia/Sydney"),
+        vec![
+            "2100-10-02T16:00:00+00:00 AEST AEDT",
+            "2101-04-02T16:00:00+00:00 AEDT AEST",
+            "2101-10-01T16:00:00+00:00 AEST AEDT",
+        ]
+    );
+    assert!(transitions_around_2101("Asia/Tokyo").is_empty());
+    let late = Tz::from_name("Europe/Dublin").unwrap().transitions(
+        &Utc.with_ymd_and_hms(2101, 3, 27, 1, 0, 0).unwrap(),
+        &Utc.with_ymd_and_hms(2101, 3, 27, 1, 0, 1).unwrap(),
+    );
+    assert_eq!(late.len(), 1);
+    assert!(late[0].new_offset().is_dst());
+
+    assert!(!start.is_dst());
+    assert!(new_york("2019-07-01 12:00").is_dst());
+    assert!(!Moment::<Utc>::utc(None::<String>, None).unwrap().is_dst());
+
+    // Dublin's winter time is negative daylight saving time in tzdata, but Irish Standard Time
+    // in summer is what Moment.js calls DST.
+    let dublin =
+        |date: &str| Moment::<Tz>::parse_in(date, "YYYY-MM-DD HH:mm", "Europe/Dublin").unwrap();
+    let format = || String::from("Z z [(]zz[)]");
+    assert!(!dublin("2019-01-15 12:00").is_dst());
+    assert_eq!(
+        dublin("2019-01-15 12:00").format(format()),
+        String::from("+00:00 GMT (Greenwich Mean Time)")
+    );
+    assert!(dublin("2019-07-15 12:00").is_dst());
+    assert_eq!(
+        dublin("2019-07-15 12:00").format(format()),
+        String::from("+01:00 IST (Irish Standard Time)")
+    );
+    let posix_dublin = PosixTz::parse("IST-1GMT0,M10.5.0,M3.5.0/1").unwrap();
+    let posix_summer: Moment<PosixTz> = dublin("2019-07-15 12:00").with_time_zone(&posix_dublin);
+    assert!(posix_summer.is_dst());
+    assert!(!posix_summer
+        .subtract(duration(vec![(6, UnitOfTime::Month)]))
+        .is_dst());
+    assert!(
+        !Moment::<Tz>::parse_in("2019-07-15 12:00", "YYYY-MM-DD HH:mm", "Africa/Windhoek")
+            .unwrap()
+            .is_dst()
+    );
+
+    // Days until the next change, for a "clocks change in 10 days" banner.
+    let next = start.transitions_until(&new_york("2100-01-01 00:00"))[0];
+    let next: Moment<Tz> = next.instant().with_timezone(&start.time_zone()).into();
+    assert_eq!(
+        next.from(&new_york("2019-02-28 09:00"), false, None),
+        String::from("in 10 days")
+    );
+}
+
+#[test]
+fn test_world_clock() {
+    let event = Moment::<FixedOffset>::new("2019-07-15T14:30:00+00:00", None)
+        .unwrap()
+        .locale(LOCALE_EN_GB.clone());
+    let zones = ["America/Los_Angeles", "Asia/Kolkata", "UTC"];
+
+    let moments = event.in_time_zones(&zones).unwrap();
+    assert_eq!(
+        moments
+            .iter()
+            .map(|moment| moment.format(String::from("LT z")))
+            .collect::<Vec<_>>(),
+        vec!["07:30 PDT", "20:00 IST", "14:30 UTC"]
+    );
+    assert_eq!(moments[0].time_zone().name(), "America/Los_Angeles");
+
+    assert_eq!(
+        event
+            .format_in_time_zones(&zones, "dddd, D MMMM LT zz")
+            .unwrap(),
+        vec![
+            "Monday, 15 July 07:30 Pacific Daylight Time",
+            "Monday, 15 July 20:00 IST",
+            "Monday, 15 July 14:30 Coordinated Universal Time",
+        ]
+    );
+    assert_eq!(
+        event.in_time_zones(&["Europe/Atlantis"]).unwrap_err(),
+        MomentError::UnknownTimeZone(String::from("Europe/Atlantis"))
+    );
+
+    let format = LOCALE_EN_GB.compile_format("L LT");
+    assert_eq!(
+        moments[1].format_compiled(&format),
+        String::from("15/07/2019 20:00")
+    );
+    assert_eq!(
+        moments[1].format(String::from("L LT")),
+        moments[1].format_compiled(&format)
+    );
+
+    // Converting to UTC keeps the locale.
+    assert_eq!(
+        event.utc().format(String::from("LLLL")),
+        String::from("Monday, 15 July 2019 14:30")
+    );
+}
+
+#[test]
+fn test_windows_time_zones() {
+    assert_eq!(
+        Tz::from_windows_name("Pacific Standard Time", None)
+            .unwrap()
+            .name(),
+        "America/Los_Angeles"
+    );
+    assert_eq!(
+        Tz::from_windows_name("Pacific Standard Time", Some("CA"))
+            .unwrap()
+            .name(),
+        "America/Vancouver"
+    );
+    // Territories without a zone of their own use the main zone.
+    assert_eq!(
+        Tz::from_windows_name("Pacific Standard Time", Some("FR"))
+            .unwrap()
+            .name(),
+        "America/Los_Angeles"
+    );
+    assert_eq!(
+        Tz::from_windows_name("w. europe standard time", Some("ch"))
+            .unwrap()
+            .name(),
+        "Europe/Zurich"
+    );
+    assert_eq!(
+        Tz::from_windows_name("Mars Standard Time", None).unwrap_err(),
+        MomentError::UnknownTimeZone(String::from("Mars Standard Time"))
+    );
+
+    for (zone, windows_name) in [
+        ("America/Los_Angeles", Some("Pacific Standard Time")),
+        ("America/Vancouver", Some("Pacific Standard Time")),
+        ("US/Pacific", Some("Pacific Standard Time")),
+        ("Europe/London", Some("GMT Standard Time")),
+        ("Asia/Kolkata", Some("India Standard Time")),
+        ("Asia/Calcutta", Some("India Standard Time")),
+        ("UTC", Some("UTC")),
+    ]
+    .iter()
+    {
+        assert_eq!(Tz::from_name(zone).unwrap().windows_name(), *windows_name);
+    }
+
+    let moment = Moment::<FixedOffset>::new("2019-07-15T14:30:00+00:00", None)
+        .unwrap()
+        .windows_tz("Tokyo Standard Time", None)
+        .unwrap();
+    assert_eq!(
+        moment.format(String::from("YYYY-MM-DD HH:mm z")),
+        String::from("2019-07-15 23:30 JST")
+    );
+    assert_eq!(
+        moment.time_zone().windows_name(),
+        Some("Tokyo Standard Time")
+    );
+    let local = NaiveDate::from_ymd_opt(2019, 1, 15)
+        .unwrap()
+        .and_hms_opt(9, 0, 0)
+        .unwrap();
+    let created = Moment::from_local(
+        &local,
+        &Tz::from_windows_name("Eastern Standard Time", Some("US")).unwrap(),
+        Disambiguation::default(),
+    )
+    .unwrap();
+    assert_eq!(
+        created.to_rfc3339(),
+        String::from("2019-01-15T09:00:00-05:00")
+    );
+}
+
+#[test]
+fn test_locale_registry() {
+    assert_eq!(find_locale("en-GB").unwrap().name, "en-gb");
+    assert_eq!(find_locale("en_gb").unwrap().name, "en-gb");
+    assert_eq!(find_locale("en-GB-oxendict").unwrap().name, "en-gb");
+    assert_eq!(find_locale("en-US").unwrap().name, "en");
+    assert!(find_locale("xx-XX").is_none());
+
+    let moment: Moment<Utc> = Utc.with_ymd_and_hms(2019, 5, 20, 15, 4, 5).unwrap().into();
+    let british = moment.clone().locale_by_name("en-GB").unwrap();
+    assert_eq!(british.locale_name(), "en-gb");
+    assert_eq!(british.format(String::from("L")), "20/05/2019");
+    assert_eq!(
+        british.utc_offset(60, false).unwrap().locale_name(),
+        "en-gb"
+    );
+    assert_eq!(
+        moment.clone().locale_by_name("xx").unwrap_err(),
+        MomentError::UnknownLocale(String::from("xx"))
+    );
+
+    // Moments share the registered locale instead of copying it.
+    let other: Moment<Utc> = Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap().into();
+    assert!(std::ptr::eq(moment.locale_data(), other.locale_data()));
+    assert!(std::ptr::eq(
+        moment.locale_by_name("en-GB").unwrap().locale_data(),
+        &*find_locale("en-GB").unwrap()
+    ));
+}
+
+#[test]
+fn test_locale_negotiation() {
+    let tag = LanguageTag::parse("zh-hant-tw-u-ca-roc").unwrap();
+    assert_eq!(tag.language(), "zh");
+    assert_eq!(tag.script(), Some("Hant"));
+    assert_eq!(tag.region(), Some("TW"));
+    assert_eq!(tag.to_string(), "zh-Hant-TW");
+    assert_eq!(LanguageTag::parse("es_419").unwrap().region(), Some("419"));
+    assert!(LanguageTag::parse("").is_none());
+    assert!(LanguageTag::parse("en--GB").is_none());
+    assert!(LanguageTag::parse("123").is_none());
+
+    let chosen = negotiate_accept_language("xx-CA,xx;q=0.9,en-GB;q=0.8,en;q=0.7").unwrap();
+    assert_eq!(chosen.locale.name, "en-gb");
+    assert_eq!(chosen.tag, "en-GB");
+    let chosen = negotiate_accept_language("en;q=0.5, en-gb;q=0.9").unwrap();
+    assert_eq!(chosen.locale.name, "en-gb");
+    let chosen = negotiate_accept_language("en-Latn-US, xx").unwrap();
+    assert_eq!(chosen.locale.name, "en");
+    assert_eq!(chosen.tag, "en-Latn-US");
+    assert_eq!(negotiate_accept_language("xx, *;q=0.1").unwrap().tag, "*");
+    assert!(negotiate_accept_language("en-GB;q=0, xx").is_none());
+    assert!(negotiate_accept_language("en-GB;q=high").is_none());
+    assert!(negotiate_accept_language("").is_none());
+
+    assert_eq!(
+        negotiate_locale(&["xx", "en-ZA"]).unwrap().locale.name,
+        "en"
+    );
+}
+
+#[cfg(all(feature = "json", feature = "toml"))]
+#[test]
+fn test_locale_files() {
+    let directory = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/locales");
+    let loaded = Locale::from_file(format!("{}/en-gb.toml", directory)).unwrap();
+    assert_eq!(loaded.name, "en-x-toml");
+    assert!(moment::locale::register_locale(loaded).is_none());
+    let moment: Moment<Utc> = Utc.with_ymd_and_hms(2019, 5, 1, 15, 4, 5).unwrap().into();
+    let compiled = moment.clone().locale(LOCALE_EN_GB.clone());
+    let loaded = moment.clone().locale_by_name("en-x-toml").unwrap();
+    for format in &[
+        "LLLL",
+        "LTS",
+        "L",
+        "Do MMM YY",
+        "DDDo [day], wo [week], Qo [quarter]",
+        "hh:mm a",
+        "A",
+        "dd ddd dddd",
+    ] {
+        assert_eq!(
+            loaded.format(String::from(*format)),
+            compiled.format(String::from(*format))
+        );
+    }
+    for day in 1..=31 {
+        let date = NaiveDate::from_ymd_opt(2019, 1, day)
+            .unwrap()
+            .and_hms_opt(0, 0, 0)
+            .unwrap();
+        let moment: Moment<Utc> = Utc.from_utc_datetime(&date).into();
+        assert_eq!(
+            moment
+                .clone()
+                .locale_by_name("en-x-toml")
+                .unwrap()
+                .format(String::from("Do")),
+            moment
+                .locale(LOCALE_EN_GB.clone())
+                .format(String::from("Do"))
+        );
+    }
+    let parsed = Moment::<FixedOffset>::parse_with_locale(
+        "1st May 2019 3:04 pm",
+        "Do MMMM YYYY h:mm a",
+        find_locale("en-x-toml").unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        parsed.format(String::from("YYYY-MM-DD HH:mm")),
+        "2019-05-01 15:04"
+    );
+
+    let french = Locale::from_file(format!("{}/fr.json", directory)).unwrap();
+    let moment = moment.locale(french);
+    assert_eq!(
+        moment.format(String::from("dddd Do MMMM YYYY")),
+        "mercredi 1er mai 2019"
+    );
+    assert_eq!(
+        moment
+            .clone()
+            .add(duration(vec![(1, UnitOfTime::Day)]))
+            .format(String::from("Do MMM")),
+        "2 mai"
+    );
+
+    assert!(matches!(
+        Locale::from_json(r#"{"name": "broken"}"#),
+        Err(MomentError::InvalidLocale(_))
+    ));
+    let broken = std::fs::read_to_string(format!("{}/fr.json", directory))
+        .unwrap()
+        .replace(r#""pm_parse": "(?i)^p""#, r#""pm_parse": "(""#);
+    assert!(matches!(
+        Locale::from_json(&broken),
+        Err(MomentError::InvalidLocale(_))
+    ));
+    assert!(matches!(
+        Locale::from_file(format!("{}/missing.json", directory)),
+        Err(MomentError::InvalidLocale(_))
+    ));
+    assert!(matches!(
+        Locale::from_file(format!("{}/../test.rs", directory)),
+        Err(MomentError::InvalidLocale(_))
+    ));
+}
+
+#[cfg(feature = "generated-locales")]
+#[test]
+fn test_locale_pack() {
+    let moment: Moment<Utc> = Utc.with_ymd_and_hms(2019, 5, 2, 15, 4, 5).unwrap().into();
+    let formats = [
+        ("en-AU", "Thursday, 2 May 2019 3:04 PM", "02/05/2019"),
+        ("en-CA", "Thursday, May 2, 2019 3:04 PM", "2019-05-02"),
+        ("en-IE", "Thursday 2 May 2019 15:04", "02/05/2019"),
+        ("en-IL", "Thursday, 2 May 2019 15:04", "02/05/2019"),
+        ("en-IN", "Thursday, 2 May 2019 3:04 PM", "02/05/2019"),
+        ("en-NZ", "Thursday, 2 May 2019 3:04 PM", "02/05/2019"),
+        ("en-SG", "Thursday, 2 May 2019 15:04", "02/05/2019"),
+    ];
+    for (tag, long, short) in formats.iter() {
+        let localized = moment.clone().locale_by_name(tag).unwrap();
+        assert_eq!(localized.locale_name(), tag.to_lowercase());
+        assert_eq!(localized.format(String::from("LLLL")), *long);
+        assert_eq!(localized.format(String::from("L")), *short);
+        assert_eq!(localized.format(String::from("Do")), "2nd");
+    }
+    let australian = moment.locale_by_name("en-AU").unwrap();
+    assert_eq!(
+        australian.calendar(
+            australian.clone().add(duration(vec![(1, UnitOfTime::Day)])),
+            None,
+            None
+        ),
+        "Yesterday at 3:04 PM"
+    );
+    assert_eq!(
+        australian
+            .locale_data()
+            .humanize(&duration(vec![(3, UnitOfTime::Day)]), false, None),
+        "in 3 days"
+    );
+}
+
+// Outputs of Moment.js 2.30 for the same calls.
+#[cfg(feature = "generated-locales")]
+#[test]
+fn test_locale_pack_moment_parity() {
+    let moment = Moment::parse("2010-02-14 15:25:50", "YYYY-MM-DD HH:mm:ss").unwrap();
+    let cases = [
+        (
+            "de",
+            "Sonntag, 14. Februar 2010 15:25",
+            "14 Feb. 2010",
+            ["ein paar Sekunden", "eine Minute", "44 Minuten", "5 Tage"],
+            [
+                "in ein paar Sekunden",
+                "vor einem Tag",
+                "in 5 Tagen",
+                "vor 2 Jahren",
+            ],
+        ),
+        (
+            "fr",
+            "dimanche 14 février 2010 15:25",
+            "14 févr. 2010",
+            ["quelques secondes", "une minute", "44 minutes", "5 jours"],
+            [
+                "dans quelques secondes",
+                "il y a un jour",
+                "dans 5 jours",
+                "il y a 2 ans",
+            ],
+        ),
+    ];
+    for (tag, long, short, without_suffix, with_suffix) in cases.iter() {
+        let start = moment.clone().locale_by_name(tag).unwrap();
+        let later = |duration: MomentDuration| start.clone().add(duration);
+        assert_eq!(start.format(String::from("LLLL")), *long);
+        assert_eq!(start.format(String::from("D MMM YYYY")), *short);
+
+        let humanized = [
+            MomentDuration::new().seconds(44),
+            MomentDuration::new().seconds(45),
+            MomentDuration::new().minutes(44),
+            MomentDuration::new().days(5),
+        ]
+        .iter()
+        .map(|duration| later(*duration).from(&start, true, None))
+        .collect::<Vec<_>>();
+        assert_eq!(humanized, *without_suffix, "{}", tag);
+
+        let relative = vec![
+            later(MomentDuration::new().seconds(30)).from(&start, false, None),
+            start.from(&later(MomentDuration::new().days(1)), false, None),
+            later(MomentDuration::new().days(5)).from(&start, false, None),
+            start.from(&later(MomentDuration::new().years(2)), false, None),
+        ];
+        assert_eq!(relative, *with_suffix, "{}", tag);
+    }
+
+    let german = moment.locale_by_name("de").unwrap();
+    assert_eq!(german.format(String::from("Do MMMM")), "14. Februar");
+    assert_eq!(
+        german.calendar(german.clone(), None, None),
+        "heute um 15:25 Uhr"
+    );
+    let french = Moment::parse("2010-02-01", "YYYY-MM-DD")
+        .unwrap()
+        .locale_by_name("fr")
+        .unwrap();
+    assert_eq!(french.format(String::from("Do MMMM")), "1er février");
+    assert_eq!(french.format(String::from("dddd")), "lundi");
+}
+
+#[test]
+fn test_plural_relative_time() {
+    use PluralCategory::*;
+
+    let categories = |language: &str, numbers: &[f64]| {
+        numbers
+            .iter()
+            .map(|number| plural_category(language, *number))
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(
+        categories("en", &[1.0, 2.0, 0.0, 1.5]),
+        vec![One, Other, Other, Other]
+    );
+    assert_eq!(
+        categories("fr", &[0.0, 1.5, 2.0, 1e6]),
+        vec![One, One, Other, Many]
+    );
+    assert_eq!(
+        categories("ru", &[1.0, 21.0, 11.0, 3.0, 12.0, 5.0, 1.5]),
+        vec![One, One, Many, Few, Many, Many, Other]
+    );
+    assert_eq!(
+        categories("pl", &[1.0, 21.0, 22.0, 12.0, 1.5]),
+        vec![One, Many, Few, Many, Other]
+    );
+    assert_eq!(
+        categories("cs-CZ", &[1.0, 3.0, 5.0, 1.5]),
+        vec![One, Few, Other, Many]
+    );
+    assert_eq!(
+        categories("ar", &[0.0, 1.0, 2.0, 3.0, 11.0, 100.0, 102.0]),
+        vec![Zero, One, Two, Few, Many, Other, Other]
+    );
+    assert_eq!(categories("pt-PT", &[0.0, 1.0]), vec![Other, One]);
+    assert_eq!(categories("pt-BR", &[0.0, 1.0]), vec![One, One]);
+    assert_eq!(categories("ja", &[1.0]), vec![Other]);
+
+    let plural = |one: &'static str, few: &'static str, many: &'static str| {
+        RelativeTimeFormat::from(PluralForms {
+            zero: None,
+            one: Some(one.into()),
+            two: None,
+            few: Some(few.into()),
+            many: Some(many.into()),
+            other: few.into(),
+        })
+    };
+    let mut russian = Locale {
+        name: "ru".into(),
+        ..LOCALE_EN_US.clone()
+    };
+    russian.relative_time.future = "через %s".into();
+    russian.relative_time.past = "%s назад".into();
+    russian.relative_time.m = RelativeTimeFormat::Function(|_, without_suffix, _, _| {
+        String::from(if without_suffix {
+            "минута"
+        } else {
+            "минуту"
+        })
+    });
+    // Like Moment.js's `ru` locale, minutes change case after "через".
+    russian.relative_time.mm = RelativeTimeFormat::Function(|number, without_suffix, _, _| {
+        let forms = if without_suffix {
+            ["минута", "минуты", "минут"]
+        } else {
+            ["минуту", "минуты", "минут"]
+        };
+        let form = match plural_category("ru", number) {
+            PluralCategory::One => forms[0],
+            PluralCategory::Few => forms[1],
+            _ => forms[2],
+        };
+        format!("{} {}", number, form)
+    });
+    russian.relative_time.dd = plural("%d день", "%d дня", "%d дней");
+
+    let minutes = |count: i64| duration(vec![(count, UnitOfTime::Minute)]);
+    assert_eq!(russian.humanize(&minutes(1), false, None), "через минуту");
+    assert_eq!(russian.humanize(&minutes(-1), true, None), "минута");
+    assert_eq!(
+        russian.humanize(&minutes(21), false, None),
+        "через 21 минуту"
+    );
+    assert_eq!(russian.humanize(&minutes(-21), true, None), "21 минута");
+    assert_eq!(
+        russian.humanize(&minutes(-3), false, None),
+        "3 минуты назад"
+    );
+    assert_eq!(russian.humanize(&minutes(11), true, None), "11 минут");
+    assert_eq!(
+        russian.humanize(&duration(vec![(5, UnitOfTime::Day)]), false, None),
+        "через 5 дней"
+    );
+    assert_eq!(
+        russian.humanize(&duration(vec![(-21, UnitOfTime::Day)]), false, None),
+        "21 день назад"
+    );
+
+    // The singular keys get 1, as in Moment.js, whatever the rounding leaves.
+    let config = RelativeTimeConfig {
+        rounding: |number| (number * 10_f64).round() / 10_f64,
+        ..RelativeTimeConfig::default()
+    };
+    let mut singular = LOCALE_EN_US.clone();
+    singular.relative_time.m =
+        RelativeTimeFormat::Function(|number, _, key, _| format!("{}({})", key, number));
+    singular.relative_time.h = plural("one hour", "few hours", "many hours");
+    assert_eq!(
+        singular.humanize(
+            &duration(vec![(48, UnitOfTime::Second)]),
+            false,
+            Some(&config)
+        ),
+        "in m(1)"
+    );
+    assert_eq!(
+        singular.humanize(&minutes(-54), false, Some(&config)),
+        "one hour ago"
+    );
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn test_plural_relative_time_from_json() {
+    let config = RelativeTimeConfig {
+        rounding: |number| (number * 10_f64).round() / 10_f64,
+        ..RelativeTimeConfig::default()
+    };
+    let mut data = std::fs::read_to_string(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/locales/fr.json"
+    ))
+    .unwrap();
+    data = data.replace(
+        r#""dd": "%d jours""#,
+        r#""dd": {"one": "%d jour", "other": "%d jours"}"#,
+    );
+    let french = Locale::from_json(&data).unwrap();
+    assert!(matches!(
+        french.relative_time.dd,
+        RelativeTimeFormat::Plural(_)
+    ));
+    assert!(matches!(
+        french.relative_time.d,
+        RelativeTimeFormat::Template(_)
+    ));
+    let days = |hours: i64| duration(vec![(hours, UnitOfTime::Hour)]);
+    assert_eq!(
+        french.humanize(&days(36), false, Some(&config)),
+        "dans 1.5 jour"
+    );
+    assert_eq!(
+        french.humanize(&days(-72), false, Some(&config)),
+        "il y a 3 jours"
+    );
+}
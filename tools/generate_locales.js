@@ -0,0 +1,640 @@
+#!/usr/bin/env node
+// Generates `src/locales/*.rs` from the Moment.js locale definitions.
+//
+//     npm pack moment && tar xzf moment-*.tgz
+//     node tools/generate_locales.js package/locale
+//
+// Each locale file is evaluated with a stand-in for `moment` that records the configuration passed
+// to `defineLocale`. Functions in the configuration are sampled and turned into the declarative
+// rules of `Locale` where that gives the same output. Relative times that change with the suffix
+// become generated functions that pick a template by suffix, direction and plural category.
+// Locales that cannot be represented are reported and skipped: those that substitute digits
+// (ar, hi, ...), whose calendar strings depend on the weekday or hour (ru, pl, cs, uk, es, ...) or
+// whose month names change with the format. So only part of Moment.js's locale pack is generated;
+// the `generated-locales` feature enables everything that was. The script rewrites
+// `src/locales/mod.rs` and the locale features in `Cargo.toml`.
+
+'use strict';
+
+const fs = require('fs');
+const path = require('path');
+const vm = require('vm');
+
+const ROOT = path.join(__dirname, '..');
+const LOCALES_DIRECTORY = path.join(ROOT, 'src', 'locales');
+const CARGO_TOML = path.join(ROOT, 'Cargo.toml');
+
+// Locales that are always compiled in and maintained by hand.
+const HAND_WRITTEN = new Set(['en', 'en-gb']);
+
+// Moment.js's `baseConfig`, which every locale extends.
+const BASE_CONFIG = {
+    calendar: {
+        sameDay: '[Today at] LT',
+        nextDay: '[Tomorrow at] LT',
+        nextWeek: 'dddd [at] LT',
+        lastDay: '[Yesterday at] LT',
+        lastWeek: '[Last] dddd [at] LT',
+        sameElse: 'L',
+    },
+    longDateFormat: {
+        LTS: 'h:mm:ss A',
+        LT: 'h:mm A',
+        L: 'MM/DD/YYYY',
+        LL: 'MMMM D, YYYY',
+        LLL: 'MMMM D, YYYY h:mm A',
+        LLLL: 'dddd, MMMM D, YYYY h:mm A',
+    },
+    invalidDate: 'Invalid date',
+    ordinal: '%d',
+    dayOfMonthOrdinalParse: /\d{1,2}/,
+    relativeTime: {
+        future: 'in %s',
+        past: '%s ago',
+        s: 'a few seconds',
+        ss: '%d seconds',
+        m: 'a minute',
+        mm: '%d minutes',
+        h: 'an hour',
+        hh: '%d hours',
+        d: 'a day',
+        dd: '%d days',
+        w: 'a week',
+        ww: '%d weeks',
+        M: 'a month',
+        MM: '%d months',
+        y: 'a year',
+        yy: '%d years',
+    },
+    months: ['January', 'February', 'March', 'April', 'May', 'June', 'July', 'August',
+        'September', 'October', 'November', 'December'],
+    monthsShort: ['Jan', 'Feb', 'Mar', 'Apr', 'May', 'Jun', 'Jul', 'Aug', 'Sep', 'Oct', 'Nov', 'Dec'],
+    week: { dow: 0, doy: 6 },
+    weekdays: ['Sunday', 'Monday', 'Tuesday', 'Wednesday', 'Thursday', 'Friday', 'Saturday'],
+    weekdaysMin: ['Su', 'Mo', 'Tu', 'We', 'Th', 'Fr', 'Sa'],
+    weekdaysShort: ['Sun', 'Mon', 'Tue', 'Wed', 'Thu', 'Fri', 'Sat'],
+    meridiemParse: /[ap]\.?m?\.?/i,
+    meridiem: (hours) => (hours > 11 ? 'PM' : 'AM'),
+    isPM: (input) => (input + '').toLowerCase().charAt(0) === 'p',
+};
+
+class Unsupported extends Error {}
+
+// Locale files run in their own context, so their regular expressions are not `instanceof RegExp`.
+function isRegExp(value) {
+    return Object.prototype.toString.call(value) === '[object RegExp]';
+}
+
+function loadDefinitions(directory) {
+    const definitions = new Map();
+    const moment = {
+        defineLocale(name, config) {
+            definitions.set(name, config);
+        },
+        updateLocale(name, config) {
+            definitions.set(name, Object.assign({}, definitions.get(name), config));
+        },
+        locale() {},
+    };
+    for (const file of fs.readdirSync(directory).sort()) {
+        if (!file.endsWith('.js')) {
+            continue;
+        }
+        const source = fs.readFileSync(path.join(directory, file), 'utf8');
+        const module = { exports: {} };
+        vm.runInNewContext(source, {
+            module,
+            exports: module.exports,
+            require: () => moment,
+            define: undefined,
+            moment,
+        }, { filename: file });
+    }
+    return definitions;
+}
+
+function resolveConfig(name, definitions) {
+    const config = definitions.get(name);
+    const parent = definitions.has(config.parentLocale)
+        ? resolveConfig(config.parentLocale, definitions)
+        : BASE_CONFIG;
+    const resolved = Object.assign({}, parent);
+    for (const [key, value] of Object.entries(config)) {
+        const isPlainObject = value && typeof value === 'object' && !Array.isArray(value)
+            && !isRegExp(value) && !value.format;
+        resolved[key] = isPlainObject ? Object.assign({}, parent[key], value) : value;
+    }
+    return resolved;
+}
+
+// A stand-in for the moment that locale functions are called on.
+function fakeMoment(fields) {
+    const get = (key) => () => fields[key] || 0;
+    return {
+        month: get('month'),
+        day: get('day'),
+        date: get('date'),
+        hours: get('hour'),
+        hour: get('hour'),
+        minutes: get('minute'),
+        minute: get('minute'),
+        year: () => 2019,
+        isSame: () => false,
+        isBefore: () => false,
+        isAfter: () => false,
+        week: get('week'),
+    };
+}
+
+function names(value, count, field, format) {
+    if (Array.isArray(value)) {
+        return value;
+    }
+    if (typeof value === 'function') {
+        return Array.from({ length: count }, (_, index) =>
+            value.call(null, fakeMoment({ [field]: index }), format));
+    }
+    if (value && Array.isArray(value.format)) {
+        return field === 'month' ? value.format : value.standalone || value.format;
+    }
+    throw new Unsupported(`cannot read the ${field} names`);
+}
+
+function checkNoPreparse(config) {
+    if (config.preparse || config.postformat) {
+        throw new Unsupported('substitutes digits with preparse/postformat');
+    }
+}
+
+function calendarString(key, value) {
+    if (typeof value === 'string') {
+        return value;
+    }
+    const outputs = new Set();
+    for (let day = 0; day < 7; day++) {
+        for (let hour = 0; hour < 24; hour++) {
+            const moment = fakeMoment({ day, hour });
+            outputs.add(value.call(moment, fakeMoment({}), moment));
+        }
+    }
+    if (outputs.size !== 1) {
+        throw new Unsupported(`calendar.${key} depends on the date`);
+    }
+    return [...outputs][0];
+}
+
+// Groups the templates sampled for each number into the templates for each CLDR plural category
+// of `name`'s language, or a single `other` template if the number is only substituted.
+function pluralForms(name, key, templates) {
+    const distinct = new Set(templates.values());
+    if (distinct.size === 1) {
+        return { other: [...distinct][0] };
+    }
+    const pluralRules = new Intl.PluralRules(name);
+    const forms = {};
+    for (const [sample, template] of templates) {
+        if (!Number.isInteger(sample)) {
+            continue;
+        }
+        const category = pluralRules.select(sample);
+        if (forms[category] !== undefined && forms[category] !== template) {
+            throw new Unsupported(`relativeTime.${key} does not follow the plural rules`);
+        }
+        forms[category] = template;
+    }
+    if (forms.other === undefined) {
+        forms.other = templates.get(1.5);
+    }
+    return forms;
+}
+
+// Turns a relative time entry into a `%d` or `%s` template if it is one, into templates for each
+// CLDR plural category of `name`'s language if the entry only depends on the number, or into
+// those forms for each suffix and direction if it depends on them too.
+function relativeTimeString(name, key, value) {
+    if (typeof value === 'string') {
+        return value;
+    }
+    if (key === 'future' || key === 'past') {
+        const templates = new Set(['\u0001', '1 \u0001', '12 \u0001']
+            .map((sample) => value(sample).split(sample).join('%s')));
+        if (templates.size !== 1) {
+            throw new Unsupported(`relativeTime.${key} depends on the duration`);
+        }
+        return [...templates][0];
+    }
+    // Moment.js passes 1 for the singular keys, except for `s`, which gets the number of seconds.
+    // Fractions are only passed with a custom rounding; 1.5 is sampled for languages that use the
+    // `other` category only for fractions.
+    let samples = Array.from({ length: 200 }, (_, index) => index + 2).concat([1.5]);
+    if (key === 's') {
+        samples = [1, 5, 44];
+    } else if (key.length === 1) {
+        samples = [1];
+    }
+    const variants = [];
+    for (const withoutSuffix of [true, false]) {
+        for (const isFuture of [true, false]) {
+            const templates = new Map(samples.map((sample) => [
+                sample,
+                value(sample, withoutSuffix, key, isFuture).split(String(sample)).join('%d'),
+            ]));
+            variants.push({ withoutSuffix, isFuture, forms: pluralForms(name, key, templates) });
+        }
+    }
+    const distinct = new Set(variants.map((variant) => JSON.stringify(variant.forms)));
+    if (distinct.size > 1) {
+        return { variants };
+    }
+    const forms = variants[0].forms;
+    return Object.keys(forms).length === 1 ? forms.other : { forms };
+}
+
+// Finds rules that reproduce `ordinal` for 0 to 1000: exact values first, then the value modulo
+// 100, then modulo 10, then a default.
+function ordinalRules(ordinal) {
+    if (typeof ordinal === 'string') {
+        return { rules: [], default: ordinal };
+    }
+    const template = (number) => {
+        const output = String(ordinal(number, 'D'));
+        if (!output.includes(String(number))) {
+            throw new Unsupported('ordinal does not contain the number');
+        }
+        return output.replace(String(number), '%d');
+    };
+    const templates = Array.from({ length: 1001 }, (_, number) => template(number));
+    const counts = new Map();
+    for (const value of templates) {
+        counts.set(value, (counts.get(value) || 0) + 1);
+    }
+    const fallback = [...counts.entries()].sort((a, b) => b[1] - a[1])[0][0];
+
+    const group = (modulo, values) => {
+        const byTemplate = new Map();
+        for (const [value, format] of values) {
+            if (!byTemplate.has(format)) {
+                byTemplate.set(format, []);
+            }
+            byTemplate.get(format).push(value);
+        }
+        return [...byTemplate.entries()].map(([format, values]) => ({ modulo, values, format }));
+    };
+    const apply = (rules, number) => {
+        const rule = rules.find((rule) =>
+            rule.values.includes(rule.modulo ? number % rule.modulo : number));
+        return rule ? rule.format : fallback;
+    };
+
+    // The template for each last digit, from numbers outside the teens.
+    const byLastDigit = new Map();
+    for (let number = 20; number < 100; number++) {
+        if (templates[number] !== fallback) {
+            byLastDigit.set(number % 10, templates[number]);
+        }
+    }
+    const lastDigitRules = group(10, byLastDigit);
+    const byLastTwoDigits = new Map();
+    for (let number = 100; number < 200; number++) {
+        if (templates[number] !== apply(lastDigitRules, number)) {
+            byLastTwoDigits.set(number % 100, templates[number]);
+        }
+    }
+    let rules = group(100, byLastTwoDigits).concat(lastDigitRules);
+    const exact = new Map();
+    for (let number = 0; number <= 1000; number++) {
+        if (templates[number] !== apply(rules, number)) {
+            exact.set(number, templates[number]);
+        }
+    }
+    if (exact.size > 20) {
+        throw new Unsupported('ordinal has no simple rules');
+    }
+    rules = group(null, exact).concat(rules);
+    return { rules, default: fallback };
+}
+
+// Splits the day into the periods that `meridiem` returns.
+function meridiemRules(meridiem) {
+    const rules = [];
+    let current = null;
+    for (let hour = 0; hour < 24; hour++) {
+        for (let minute = 0; minute < 60; minute++) {
+            const text = meridiem(hour, minute, false);
+            if (current === null) {
+                current = text;
+            } else if (text !== current) {
+                rules.push({ before: hour * 100 + minute, text: current });
+                current = text;
+            }
+        }
+    }
+    return { rules, default: current };
+}
+
+function pmPattern(config) {
+    if (config.isPM === BASE_CONFIG.isPM) {
+        return '(?i)^p';
+    }
+    const texts = new Set();
+    for (let hour = 12; hour < 24; hour++) {
+        for (const minute of [0, 30]) {
+            for (const text of [config.meridiem(hour, minute, false), config.meridiem(hour, minute, true)]) {
+                if (config.isPM(text)) {
+                    texts.add(text);
+                }
+            }
+        }
+    }
+    if (texts.size === 0) {
+        throw new Unsupported('cannot tell which meridiems are after noon');
+    }
+    return `(?i)^(?:${[...texts].map(escapeRegex).join('|')})`;
+}
+
+function escapeRegex(text) {
+    return text.replace(/[.*+?^${}()|[\]\\]/g, '\\$&');
+}
+
+function regexSource(regex) {
+    const source = isRegExp(regex) ? regex.source : String(regex);
+    const flags = isRegExp(regex) && regex.ignoreCase ? '(?i)' : '';
+    return flags + source;
+}
+
+function toLocale(name, config) {
+    checkNoPreparse(config);
+    const calendar = {};
+    for (const key of ['sameDay', 'nextDay', 'nextWeek', 'lastDay', 'lastWeek', 'sameElse']) {
+        calendar[key] = calendarString(key, config.calendar[key]);
+    }
+    const relativeTime = {};
+    for (const key of Object.keys(BASE_CONFIG.relativeTime)) {
+        relativeTime[key] = relativeTimeString(name, key, config.relativeTime[key]);
+    }
+    return {
+        name,
+        invalidDate: config.invalidDate,
+        months: names(config.months, 12, 'month', 'D MMMM'),
+        monthsShort: names(config.monthsShort, 12, 'month', 'D MMM'),
+        weekdays: names(config.weekdays, 7, 'day', 'dddd'),
+        weekdaysShort: names(config.weekdaysShort, 7, 'day', 'ddd'),
+        weekdaysMin: names(config.weekdaysMin, 7, 'day', 'dd'),
+        longDateFormat: config.longDateFormat,
+        calendar,
+        relativeTime,
+        dayOfMonthOrdinalParse: regexSource(config.dayOfMonthOrdinalParse || config.ordinalParse),
+        ordinal: ordinalRules(config.ordinal),
+        week: config.week,
+        meridiem: meridiemRules(config.meridiem),
+        meridiemParse: regexSource(config.meridiemParse),
+        pmParse: pmPattern(config),
+    };
+}
+
+function rustStr(value) {
+    return JSON.stringify(value).replace(/\\u([0-9a-fA-F]{4})/g, '\\u{$1}');
+}
+
+function rustString(value) {
+    return rustStr(value) + '.into()';
+}
+
+function rustRegex(source) {
+    let hashes = source.includes('"') ? '#' : '';
+    while (source.includes('"' + hashes)) {
+        hashes += '#';
+    }
+    return `Regex::new(r${hashes}"${source}"${hashes}).unwrap()`;
+}
+
+const PLURAL_CATEGORIES = ['zero', 'one', 'two', 'few', 'many'];
+
+function relativeTimeFunctionName(key) {
+    return `relative_time_${key}`;
+}
+
+function rustRelativeTime(key, value) {
+    if (typeof value === 'string') {
+        return rustString(value);
+    }
+    if (value.variants) {
+        return `RelativeTimeFormat::Function(${relativeTimeFunctionName(key)})`;
+    }
+    const form = (category) => value.forms[category] === undefined
+        ? 'None'
+        : `Some(${rustString(value.forms[category])})`;
+    return `PluralForms {
+                zero: ${form('zero')},
+                one: ${form('one')},
+                two: ${form('two')},
+                few: ${form('few')},
+                many: ${form('many')},
+                other: ${rustString(value.forms.other)},
+            }
+            .into()`;
+}
+
+// A function for an entry that changes with the suffix or direction, matching on both and on the
+// plural category of the number.
+function rustRelativeTimeFunction(language, key, variants) {
+    const variant = (withoutSuffix, isFuture) => variants.find((variant) =>
+        variant.withoutSuffix === withoutSuffix && variant.isFuture === isFuture).forms;
+    const groups = [];
+    for (const withoutSuffix of [true, false]) {
+        const future = variant(withoutSuffix, true);
+        const past = variant(withoutSuffix, false);
+        if (JSON.stringify(future) === JSON.stringify(past)) {
+            groups.push({ pattern: `${withoutSuffix}, _`, forms: future });
+        } else {
+            groups.push({ pattern: `${withoutSuffix}, true`, forms: future });
+            groups.push({ pattern: `${withoutSuffix}, false`, forms: past });
+        }
+    }
+    const isPlural = groups.some((group) => Object.keys(group.forms).length > 1);
+    const arms = groups.flatMap((group) => {
+        if (!isPlural) {
+            return [`        (${group.pattern}) => ${rustStr(group.forms.other)},`];
+        }
+        return PLURAL_CATEGORIES
+            .filter((category) => group.forms[category] !== undefined)
+            .map((category) => {
+                const variantName = category[0].toUpperCase() + category.slice(1);
+                return `        (${group.pattern}, PluralCategory::${variantName}) => ${rustStr(group.forms[category])},`;
+            })
+            .concat([`        (${group.pattern}, _) => ${rustStr(group.forms.other)},`]);
+    });
+    const scrutinee = isPlural
+        ? `(without_suffix, is_future, plural_category(${rustStr(language)}, number))`
+        : '(without_suffix, is_future)';
+    // `M` and `MM` are the only keys that aren't snake case.
+    const allow = key === key.toLowerCase() ? '' : '#[allow(non_snake_case)]\n';
+    return `
+${allow}fn ${relativeTimeFunctionName(key)}(number: f64, without_suffix: bool, _: &str, is_future: bool) -> String {
+    let template = match ${scrutinee} {
+${arms.join('\n')}
+    };
+    template.replacen("%d", &number.to_string(), 1)
+}
+`;
+}
+
+function identifier(name) {
+    return name.toUpperCase().replace(/-/g, '_');
+}
+
+function rustLocale(locale) {
+    const list = (values) => values.map((value) => `            ${rustString(value)},`).join('\n');
+    const fields = (object, keys, indent) => keys
+        .map((key) => `${indent}${key}: ${rustString(object[key])},`).join('\n');
+    const ordinalRule = (rule) => `                OrdinalRule {
+                    modulo: ${rule.modulo ? `Some(${rule.modulo})` : 'None'},
+                    values: vec![${rule.values.join(', ')}],
+                    format: ${rustString(rule.format)},
+                }`;
+    const meridiemRule = (rule) => `                MeridiemRule {
+                    before: ${rule.before},
+                    text: ${rustString(rule.text)},
+                }`;
+    const rules = (values, format) => values.length === 0
+        ? 'vec![]'
+        : `vec![\n${values.map(format).join(',\n')}${values.length > 1 ? ',' : ''}\n            ]`;
+    return `// Generated by tools/generate_locales.js from Moment.js's \`${locale.name}\` locale.
+use crate::locale::*;
+use lazy_static::lazy_static;
+use regex::Regex;
+
+lazy_static! {
+    pub static ref LOCALE_${identifier(locale.name)}: Locale = Locale {
+        name: ${rustString(locale.name)},
+        invalid_date: ${rustString(locale.invalidDate)},
+        months: MonthStrings(
+${list(locale.months)}
+        ),
+        months_short: MonthStrings(
+${list(locale.monthsShort)}
+        ),
+        weekdays: WeekDayStrings(
+${list(locale.weekdays)}
+        ),
+        weekdays_short: WeekDayStrings(
+${list(locale.weekdaysShort)}
+        ),
+        weekdays_min: WeekDayStrings(
+${list(locale.weekdaysMin)}
+        ),
+        long_date_format: LongDateFormat {
+${fields(locale.longDateFormat, ['LT', 'LTS', 'L', 'LL', 'LLL', 'LLLL'], '            ')}
+        },
+        calendar: Calendar {
+            same_day: ${rustString(locale.calendar.sameDay)},
+            next_day: ${rustString(locale.calendar.nextDay)},
+            next_week: ${rustString(locale.calendar.nextWeek)},
+            last_day: ${rustString(locale.calendar.lastDay)},
+            last_week: ${rustString(locale.calendar.lastWeek)},
+            same_else: ${rustString(locale.calendar.sameElse)},
+        },
+        relative_time: RelativeTime {
+${Object.keys(BASE_CONFIG.relativeTime)
+        .map((key) => `            ${key}: ${rustRelativeTime(key, locale.relativeTime[key])},`).join('\n')}
+        },
+        time_zone_names: TimeZoneNames::default(),
+        day_of_month_ordinal_parse: ${rustRegex(locale.dayOfMonthOrdinalParse)},
+        ordinal: Ordinal {
+            rules: ${rules(locale.ordinal.rules, ordinalRule)},
+            default: ${rustString(locale.ordinal.default)},
+        },
+        week: Week {
+            dow: ${locale.week.dow},
+            doy: ${locale.week.doy},
+        },
+        meridiem: Meridiem {
+            rules: ${rules(locale.meridiem.rules, meridiemRule)},
+            default: ${rustString(locale.meridiem.default)},
+        },
+        meridiem_parse: ${rustRegex(locale.meridiemParse)},
+        pm_parse: ${rustRegex(locale.pmParse)},
+    };
+}
+${Object.entries(locale.relativeTime)
+        .filter(([, value]) => value.variants)
+        .map(([key, value]) => rustRelativeTimeFunction(locale.name, key, value.variants))
+        .join('')}`;
+}
+
+function rustModule(names) {
+    const feature = (name) => `#[cfg(feature = "locale-${name}")]`;
+    const module = (name) => name.replace(/-/g, '_');
+    const declarations = names.map((name) => `${feature(name)}
+mod ${module(name)};
+${feature(name)}
+pub use ${module(name)}::LOCALE_${identifier(name)};`).join('\n');
+    const pushes = names.map((name) => `    ${feature(name)}
+    locales.push(LOCALE_${identifier(name)}.clone());`).join('\n');
+    return `// Generated by tools/generate_locales.js. Each Moment.js locale is behind a \`locale-*\` feature.
+use crate::locale::Locale;
+
+mod en_gb;
+pub use en_gb::LOCALE_EN_GB;
+
+mod en_us;
+pub use en_us::LOCALE_EN_US;
+
+${declarations}
+
+/// The compiled-in locales, which are registered when the registry is first used.
+pub(crate) fn built_in_locales() -> Vec<Locale> {
+    #[allow(unused_mut)]
+    let mut locales = vec![LOCALE_EN_US.clone(), LOCALE_EN_GB.clone()];
+${pushes}
+    locales
+}
+`;
+}
+
+function updateCargoToml(names) {
+    const begin = '# BEGIN GENERATED LOCALE FEATURES';
+    const end = '# END GENERATED LOCALE FEATURES';
+    const cargoToml = fs.readFileSync(CARGO_TOML, 'utf8');
+    const features = names.map((name) => `locale-${name} = []`).join('\n');
+    const all = `generated-locales = [\n${names.map((name) => `    "locale-${name}",`).join('\n')}\n]`;
+    const start = cargoToml.indexOf(begin);
+    const stop = cargoToml.indexOf(end);
+    if (start < 0 || stop < 0) {
+        throw new Error(`Cargo.toml has no "${begin}" section`);
+    }
+    fs.writeFileSync(CARGO_TOML,
+        `${cargoToml.slice(0, start)}${begin}\n${all}\n${features}\n${cargoToml.slice(stop)}`);
+}
+
+function main() {
+    const directory = process.argv[2];
+    if (!directory) {
+        console.error('usage: node tools/generate_locales.js <moment/locale directory>');
+        process.exit(1);
+    }
+    const definitions = loadDefinitions(directory);
+    const generated = [];
+    for (const name of [...definitions.keys()].sort()) {
+        if (HAND_WRITTEN.has(name)) {
+            continue;
+        }
+        let locale;
+        try {
+            locale = toLocale(name, resolveConfig(name, definitions));
+        } catch (error) {
+            if (!(error instanceof Unsupported)) {
+                throw error;
+            }
+            console.warn(`skipped ${name}: ${error.message}`);
+            continue;
+        }
+        fs.writeFileSync(path.join(LOCALES_DIRECTORY, `${name.replace(/-/g, '_')}.rs`), rustLocale(locale));
+        generated.push(name);
+    }
+    fs.writeFileSync(path.join(LOCALES_DIRECTORY, 'mod.rs'), rustModule(generated));
+    updateCargoToml(generated);
+    console.log(`generated ${generated.length} locales`);
+}
+
+main();